@@ -1,22 +1,42 @@
-use tonic_prost_build::Config;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
 
 fn main() -> Result<(), anyhow::Error> {
-    let mut config = Config::default();
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
+    generate(&out_dir)?;
+
+    Ok(())
+}
+
+/// Copy the committed `generated/openapi.rs` into `OUT_DIR`, so building never needs `protoc`.
+#[cfg(not(feature = "regenerate-protos"))]
+fn generate(out_dir: &Path) -> Result<(), anyhow::Error> {
+    const GENERATED: &str = "generated/openapi.rs";
+
+    std::fs::copy(GENERATED, out_dir.join("openapi.rs"))?;
 
-    let messages = [
-        "ProtoOaApplicationAuthReq",
-        "ProtoOaAccountAuthReq",
-        "ProtoOaRefreshTokenReq",
-        "ProtoOaNewOrderReq",
-        // Add all your message types
-    ];
+    println!("cargo:rerun-if-changed={}", GENERATED);
+
+    Ok(())
+}
+
+/// Generate the `openapi` module from `protos/` into `OUT_DIR`. The committed
+/// `generated/openapi.rs` is only updated by the ignored `test_regenerate_openapi` test.
+#[cfg(feature = "regenerate-protos")]
+fn generate(out_dir: &Path) -> Result<(), anyhow::Error> {
+    use tonic_prost_build::Config;
+
+    let mut config = Config::default();
 
-    for msg in &messages {
-        config.type_attribute(msg, "#[serde(rename_all = \"camelCase\")]");
-    }
+    // The JSON transport uses the camelCase field names of the proto definitions, fields that
+    // are not set are stripped by `codec::encode_json`
+    config.message_attribute(".", "#[serde(default, rename_all = \"camelCase\")]");
 
     config
-        .out_dir("src")
+        .out_dir(out_dir)
         .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
         .compile_protos(
             &[
@@ -1,7 +1,8 @@
-use config::{Config, ConfigError, Environment, File};
-use ctrader_rs::prelude::CTraderClient;
+use config::{Config, ConfigError, File};
+use ctrader_rs::prelude::{CTraderClient, Environment};
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::time::Duration;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct App {
@@ -34,7 +35,7 @@ impl AppConfig {
         let config = Config::builder()
             // Start off by merging in the "default" configuration file
             .add_source(File::with_name("config/default"))
-            .add_source(Environment::with_prefix("APP_").try_parsing(true))
+            .add_source(config::Environment::with_prefix("APP_").try_parsing(true))
             .add_source(File::with_name(&format!("config/{run_mode}")).required(false));
 
         let config = config.build()?;
@@ -51,15 +52,21 @@ async fn main() -> Result<(), anyhow::Error> {
     // Load the configuration settings
     let config = AppConfig::new()?;
 
-    let (mut ctrader_client, messages_handle, heartbeat_handle) = CTraderClient::new(
-        config.ctrader.is_demo,
-        config.ctrader.app_client_id,
-        config.ctrader.app_access_token,
-        config.ctrader.app_client_secret,
-        config.ctrader.app_redirect_url,
-        config.ctrader.refresh_token,
-    )
-    .await?;
+    let environment = if config.ctrader.is_demo {
+        Environment::Demo
+    } else {
+        Environment::Live
+    };
+
+    let ctrader_client = CTraderClient::builder()
+        .environment(environment)
+        .client_id(config.ctrader.app_client_id)
+        .client_secret(config.ctrader.app_client_secret)
+        .access_token(config.ctrader.app_access_token)
+        .refresh_token(config.ctrader.refresh_token)
+        .redirect_url(config.ctrader.app_redirect_url)
+        .connect()
+        .await?;
 
     ctrader_client.send_application_auth_request().await?;
 
@@ -67,7 +74,9 @@ async fn main() -> Result<(), anyhow::Error> {
     //     .send_new_stop_order(1, 41, ProtoOATradeSide::BUY, 100, 50.00)
     //     .await?;
 
-    let _ = tokio::join!(messages_handle, heartbeat_handle);
+    // Log out and close the connection on Ctrl+C
+    tokio::signal::ctrl_c().await?;
+    ctrader_client.shutdown(Duration::from_secs(5)).await?;
 
     Ok(())
 }
@@ -0,0 +1,607 @@
+use crate::error::{CTraderError, CTraderResult, ErrorCode, Lagged};
+use crate::openapi::{
+    ProtoOaAccountLogoutRes, ProtoOaAssetClassListRes, ProtoOaAssetListRes,
+    ProtoOaDealOffsetListRes, ProtoOaDepthEvent, ProtoOaErrorCode, ProtoOaExecutionEvent,
+    ProtoOaGetPositionUnrealizedPnLRes, ProtoOaGetTickDataRes, ProtoOaGetTrendbarsRes,
+    ProtoOaOrderDetailsRes, ProtoOaOrderListByPositionIdRes, ProtoOaOrderType, ProtoOaQuoteType,
+    ProtoOaReconcileRes, ProtoOaSpotEvent, ProtoOaSubscribeDepthQuotesRes,
+    ProtoOaSubscribeLiveTrendbarRes, ProtoOaSubscribeSpotsRes, ProtoOaSymbolCategoryListRes,
+    ProtoOaSymbolsListRes, ProtoOaTradeSide, ProtoOaTraderRes, ProtoOaTrendbarPeriod,
+    ProtoOaUnsubscribeDepthQuotesRes, ProtoOaUnsubscribeLiveTrendbarRes,
+    ProtoOaUnsubscribeSpotsRes,
+};
+use crate::openapi::{
+    ProtoOaCashFlowHistoryListRes, ProtoOaDealListByPositionIdRes, ProtoOaDealListRes,
+    ProtoOaExpectedMarginRes, ProtoOaGetDynamicLeverageByIdRes, ProtoOaMarginCall,
+    ProtoOaMarginCallListRes, ProtoOaMarginCallUpdateRes, ProtoOaOrderListRes,
+    ProtoOaSymbolByIdRes, ProtoOaSymbolsForConversionRes,
+};
+use crate::types::{AccountSession, AmendOrder, CTraderClient, Event};
+use futures_util::stream::{Stream, StreamExt};
+
+impl Event {
+    /// Whether the event concerns the trading account.
+    /// Connection wide events, e.g. `Disconnected`, concern no account in particular.
+    pub fn is_for_account(&self, account_id: i64) -> bool {
+        match self {
+            Event::Spot(event) => event.ctid_trader_account_id == account_id,
+            Event::Execution(event) => event.ctid_trader_account_id == account_id,
+            Event::Depth(event) => event.ctid_trader_account_id == account_id,
+            Event::OrderError(event) => event.ctid_trader_account_id == account_id,
+            Event::TrailingStopLossChanged(event) => event.ctid_trader_account_id == account_id,
+            Event::MarginChanged(event) => event.ctid_trader_account_id == account_id,
+            Event::MarginCallUpdated(event) => event.ctid_trader_account_id == account_id,
+            Event::MarginCallTriggered(event) => event.ctid_trader_account_id == account_id,
+            Event::SymbolChanged(event) => event.ctid_trader_account_id == account_id,
+            Event::TraderUpdated(event) => event.ctid_trader_account_id == account_id,
+            Event::AccountDisconnected(event) => event.ctid_trader_account_id == account_id,
+            Event::AccountsTokenInvalidated(event) => {
+                event.ctid_trader_account_ids.contains(&account_id)
+            }
+            Event::Error(event) => event.ctid_trader_account_id == Some(account_id),
+            _ => false,
+        }
+    }
+}
+
+impl CTraderClient {
+    /// Authorize a trading account on the connection, unless it already is, and return a
+    /// handle to it. Any number of accounts can be authorized on the same connection.
+    /// * account_id - The `ctid_trader_account_id` of the account
+    pub async fn account(&self, account_id: i64) -> CTraderResult<AccountSession> {
+        let account = AccountSession {
+            client: self.clone(),
+            account_id,
+        };
+
+        if !account.is_authorized().await {
+            account.authorize().await?;
+        }
+
+        Ok(account)
+    }
+}
+
+impl AccountSession {
+    /// The `ctid_trader_account_id` of the account
+    pub fn account_id(&self) -> i64 {
+        self.account_id
+    }
+
+    /// The client the account is authorized on
+    pub fn client(&self) -> &CTraderClient {
+        &self.client
+    }
+
+    /// Whether the account is authorized on the connection.
+    /// Turns `false` once the account logs out or the server disconnects it.
+    pub async fn is_authorized(&self) -> bool {
+        self.client
+            .session
+            .lock()
+            .await
+            .accounts
+            .contains(&self.account_id)
+    }
+
+    /// Authorize the account again, e.g. after `Event::AccountDisconnected`.
+    /// An account the server still considers logged in counts as authorized.
+    pub async fn authorize(&self) -> CTraderResult<()> {
+        match self.client.send_set_account_request(self.account_id).await {
+            Ok(_) => Ok(()),
+            Err(CTraderError::Api {
+                code: ErrorCode::OpenApi(ProtoOaErrorCode::AlreadyLoggedIn),
+                ..
+            }) => {
+                self.client
+                    .session
+                    .lock()
+                    .await
+                    .accounts
+                    .insert(self.account_id);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Log the account out, dropping its subscriptions
+    pub async fn logout(&self) -> CTraderResult<ProtoOaAccountLogoutRes> {
+        self.client
+            .send_account_logout_request(self.account_id)
+            .await
+    }
+
+    /// Stream the events of the account, plus `Event::Lagged` when the stream falls behind
+    pub fn events(&self) -> impl Stream<Item = Event> + Send + 'static {
+        let account_id = self.account_id;
+
+        self.client.events().filter(move |event| {
+            let keep = event.is_for_account(account_id) || matches!(event, Event::Lagged { .. });
+            async move { keep }
+        })
+    }
+
+    /// Stream the execution events of the account, or `Lagged` when the stream falls behind
+    pub fn executions(
+        &self,
+    ) -> impl Stream<Item = Result<ProtoOaExecutionEvent, Lagged>> + Send + 'static {
+        self.client.executions(self.account_id)
+    }
+
+    /// Stream the spot events of a symbol subscribed by the account, or `Lagged` when the
+    /// stream falls behind
+    pub fn spots(
+        &self,
+        symbol_id: i64,
+    ) -> impl Stream<Item = Result<ProtoOaSpotEvent, Lagged>> + Send + 'static {
+        self.client.spots(self.account_id, symbol_id)
+    }
+
+    /// Stream the depth of market events of a symbol subscribed by the account, or `Lagged`
+    /// when the stream falls behind
+    pub fn depth(
+        &self,
+        symbol_id: i64,
+    ) -> impl Stream<Item = Result<ProtoOaDepthEvent, Lagged>> + Send + 'static {
+        self.client.depth(self.account_id, symbol_id)
+    }
+
+    pub async fn send_trader_request(&self) -> CTraderResult<ProtoOaTraderRes> {
+        self.client.send_trader_request(self.account_id).await
+    }
+
+    pub async fn send_new_market_order(
+        &self,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_new_market_order(self.account_id, symbol_id, trade_side, volume)
+            .await
+    }
+
+    pub async fn send_new_limit_order(
+        &self,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: f64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_new_limit_order(self.account_id, symbol_id, trade_side, volume, price)
+            .await
+    }
+
+    pub async fn send_new_stop_order(
+        &self,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: f64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_new_stop_order(self.account_id, symbol_id, trade_side, volume, price)
+            .await
+    }
+
+    pub async fn send_new_order_request(
+        &self,
+        symbol_id: i64,
+        order_type: ProtoOaOrderType,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: Option<f64>,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_new_order_request(
+                self.account_id,
+                symbol_id,
+                order_type,
+                trade_side,
+                volume,
+                price,
+            )
+            .await
+    }
+
+    pub async fn send_cancel_order_request(
+        &self,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_cancel_order_request(self.account_id, order_id)
+            .await
+    }
+
+    pub async fn send_close_position_request(
+        &self,
+        position_id: i64,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_close_position_request(self.account_id, position_id, volume)
+            .await
+    }
+
+    pub async fn send_reconcile_request(&self) -> CTraderResult<ProtoOaReconcileRes> {
+        self.client.send_reconcile_request(self.account_id).await
+    }
+
+    pub async fn send_order_details_request(
+        &self,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaOrderDetailsRes> {
+        self.client
+            .send_order_details_request(self.account_id, order_id)
+            .await
+    }
+
+    pub async fn send_order_list_by_position_id_request(
+        &self,
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListByPositionIdRes> {
+        self.client
+            .send_order_list_by_position_id_request(
+                self.account_id,
+                position_id,
+                from_timestamp,
+                to_timestamp,
+            )
+            .await
+    }
+
+    pub async fn send_deal_offset_list_request(
+        &self,
+        deal_id: i64,
+    ) -> CTraderResult<ProtoOaDealOffsetListRes> {
+        self.client
+            .send_deal_offset_list_request(self.account_id, deal_id)
+            .await
+    }
+
+    pub async fn send_get_position_unrealized_pnl_request(
+        &self,
+    ) -> CTraderResult<ProtoOaGetPositionUnrealizedPnLRes> {
+        self.client
+            .send_get_position_unrealized_pnl_request(self.account_id)
+            .await
+    }
+
+    pub async fn send_asset_list_request(&self) -> CTraderResult<ProtoOaAssetListRes> {
+        self.client.send_asset_list_request(self.account_id).await
+    }
+
+    pub async fn send_asset_class_list_request(&self) -> CTraderResult<ProtoOaAssetClassListRes> {
+        self.client
+            .send_asset_class_list_request(self.account_id)
+            .await
+    }
+
+    pub async fn send_symbol_category_list_request(
+        &self,
+    ) -> CTraderResult<ProtoOaSymbolCategoryListRes> {
+        self.client
+            .send_symbol_category_list_request(self.account_id)
+            .await
+    }
+
+    pub async fn send_symbols_list_request(
+        &self,
+        include_archived_symbols: bool,
+    ) -> CTraderResult<ProtoOaSymbolsListRes> {
+        self.client
+            .send_symbols_list_request(self.account_id, include_archived_symbols)
+            .await
+    }
+
+    pub async fn send_subscribe_spots_request(
+        &self,
+        symbol_id: Vec<i64>,
+        subscribe_to_spot_timestamp: bool,
+    ) -> CTraderResult<ProtoOaSubscribeSpotsRes> {
+        self.client
+            .send_subscribe_spots_request(self.account_id, symbol_id, subscribe_to_spot_timestamp)
+            .await
+    }
+
+    pub async fn send_unsubscribe_spots_request(
+        &self,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeSpotsRes> {
+        self.client
+            .send_unsubscribe_spots_request(self.account_id, symbol_id)
+            .await
+    }
+
+    pub async fn send_subscribe_live_trendbar_request(
+        &self,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaSubscribeLiveTrendbarRes> {
+        self.client
+            .send_subscribe_live_trendbar_request(self.account_id, symbol_id, period)
+            .await
+    }
+
+    pub async fn send_unsubscribe_live_trendbar_request(
+        &self,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaUnsubscribeLiveTrendbarRes> {
+        self.client
+            .send_unsubscribe_live_trendbar_request(self.account_id, symbol_id, period)
+            .await
+    }
+
+    pub async fn send_subscribe_depth_quotes_request(
+        &self,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSubscribeDepthQuotesRes> {
+        self.client
+            .send_subscribe_depth_quotes_request(self.account_id, symbol_id)
+            .await
+    }
+
+    pub async fn send_unsubscribe_depth_quotes_request(
+        &self,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeDepthQuotesRes> {
+        self.client
+            .send_unsubscribe_depth_quotes_request(self.account_id, symbol_id)
+            .await
+    }
+
+    pub async fn send_get_trendbars_request(
+        &self,
+        period: i32,
+        symbol_id: i64,
+        count: u32,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaGetTrendbarsRes> {
+        self.client
+            .send_get_trendbars_request(
+                self.account_id,
+                period,
+                symbol_id,
+                count,
+                from_timestamp,
+                to_timestamp,
+            )
+            .await
+    }
+
+    pub async fn send_get_tick_data_request(
+        &self,
+        quote_type: ProtoOaQuoteType,
+        symbol_id: i64,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> CTraderResult<ProtoOaGetTickDataRes> {
+        self.client
+            .send_get_tick_data_request(
+                self.account_id,
+                quote_type,
+                symbol_id,
+                from_timestamp,
+                to_timestamp,
+            )
+            .await
+    }
+
+    /// Amend a pending order of the account, see `CTraderClient::send_amend_order_request`
+    pub async fn send_amend_order_request(
+        &self,
+        order_id: i64,
+        amend: &AmendOrder,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_amend_order_request(self.account_id, order_id, amend)
+            .await
+    }
+
+    pub async fn send_amend_position_sltp_request(
+        &self,
+        position_id: i64,
+        stop_loss: Option<f64>,
+        take_profit: Option<f64>,
+        trailing_stop_loss: bool,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_amend_position_sltp_request(
+                self.account_id,
+                position_id,
+                stop_loss,
+                take_profit,
+                trailing_stop_loss,
+            )
+            .await
+    }
+
+    pub async fn send_symbol_by_id_request(
+        &self,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSymbolByIdRes> {
+        self.client
+            .send_symbol_by_id_request(self.account_id, symbol_id)
+            .await
+    }
+
+    pub async fn send_symbols_for_conversion_request(
+        &self,
+        first_asset_id: i64,
+        last_asset_id: i64,
+    ) -> CTraderResult<ProtoOaSymbolsForConversionRes> {
+        self.client
+            .send_symbols_for_conversion_request(self.account_id, first_asset_id, last_asset_id)
+            .await
+    }
+
+    pub async fn send_deal_list_request(
+        &self,
+        from_timestamp: i64,
+        to_timestamp: i64,
+        max_rows: Option<i32>,
+    ) -> CTraderResult<ProtoOaDealListRes> {
+        self.client
+            .send_deal_list_request(self.account_id, from_timestamp, to_timestamp, max_rows)
+            .await
+    }
+
+    pub async fn send_deal_list_by_position_id_request(
+        &self,
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaDealListByPositionIdRes> {
+        self.client
+            .send_deal_list_by_position_id_request(
+                self.account_id,
+                position_id,
+                from_timestamp,
+                to_timestamp,
+            )
+            .await
+    }
+
+    pub async fn send_order_list_request(
+        &self,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListRes> {
+        self.client
+            .send_order_list_request(self.account_id, from_timestamp, to_timestamp)
+            .await
+    }
+
+    pub async fn send_expected_margin_request(
+        &self,
+        symbol_id: i64,
+        volume: Vec<i64>,
+    ) -> CTraderResult<ProtoOaExpectedMarginRes> {
+        self.client
+            .send_expected_margin_request(self.account_id, symbol_id, volume)
+            .await
+    }
+
+    pub async fn send_cash_flow_history_list_request(
+        &self,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaCashFlowHistoryListRes> {
+        self.client
+            .send_cash_flow_history_list_request(self.account_id, from_timestamp, to_timestamp)
+            .await
+    }
+
+    pub async fn send_margin_call_list_request(&self) -> CTraderResult<ProtoOaMarginCallListRes> {
+        self.client
+            .send_margin_call_list_request(self.account_id)
+            .await
+    }
+
+    pub async fn send_margin_call_update_request(
+        &self,
+        margin_call: ProtoOaMarginCall,
+    ) -> CTraderResult<ProtoOaMarginCallUpdateRes> {
+        self.client
+            .send_margin_call_update_request(self.account_id, margin_call)
+            .await
+    }
+
+    pub async fn send_get_dynamic_leverage_by_id_request(
+        &self,
+        leverage_id: i64,
+    ) -> CTraderResult<ProtoOaGetDynamicLeverageByIdRes> {
+        self.client
+            .send_get_dynamic_leverage_by_id_request(self.account_id, leverage_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::codec;
+    use crate::client::mock::{connect, mock_server, next_request};
+    use crate::client::traits::Payload;
+    use crate::openapi::{
+        ProtoOaAccountAuthReq, ProtoOaAccountAuthRes, ProtoOaAccountDisconnectEvent,
+        ProtoOaTraderReq, ProtoOaTraderRes,
+    };
+    use crate::types::Event;
+    use futures_util::{SinkExt, StreamExt};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_account_session_tracks_authorization() -> anyhow::Result<()> {
+        // Authorize accounts, and disconnect accounts 8 and 7 once the trader is requested
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            let mut authorized = Vec::new();
+
+            while let Some(frame) = next_request(&mut ws_stream).await? {
+                if frame.payload_type == ProtoOaAccountAuthReq::PAYLOAD_TYPE {
+                    let req: ProtoOaAccountAuthReq = frame.payload()?;
+                    authorized.push(req.ctid_trader_account_id);
+
+                    let res = ProtoOaAccountAuthRes {
+                        ctid_trader_account_id: req.ctid_trader_account_id,
+                        ..Default::default()
+                    };
+                    ws_stream
+                        .send(codec::encode(&res, frame.client_msg_id))
+                        .await?;
+                } else {
+                    let req: ProtoOaTraderReq = frame.payload()?;
+                    let res = ProtoOaTraderRes {
+                        ctid_trader_account_id: req.ctid_trader_account_id,
+                        ..Default::default()
+                    };
+                    ws_stream
+                        .send(codec::encode(&res, frame.client_msg_id))
+                        .await?;
+
+                    for account_id in [8, 7] {
+                        let event = ProtoOaAccountDisconnectEvent {
+                            ctid_trader_account_id: account_id,
+                            ..Default::default()
+                        };
+                        ws_stream.send(codec::encode(&event, None)).await?;
+                    }
+                }
+            }
+
+            anyhow::Ok(authorized)
+        })
+        .await?;
+
+        let client = connect(url).await?;
+
+        let account = client.account(7).await?;
+        let other = client.account(8).await?;
+        // Already authorized, so no request is sent
+        client.account(7).await?;
+
+        assert!(account.is_authorized().await);
+        assert!(other.is_authorized().await);
+
+        let mut events = Box::pin(account.events());
+        let trader = account.send_trader_request().await?;
+        assert_eq!(trader.ctid_trader_account_id, 7);
+
+        match tokio::time::timeout(Duration::from_secs(5), events.next()).await? {
+            Some(Event::AccountDisconnected(event)) => assert_eq!(event.ctid_trader_account_id, 7),
+            other => panic!("expected the disconnect of account 7, got {:?}", other),
+        }
+        assert!(!account.is_authorized().await);
+        assert!(!other.is_authorized().await);
+
+        client.shutdown(Duration::from_secs(5)).await?;
+        assert_eq!(server_handle.await??, vec![7, 8]);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
 use super::endpoint::Endpoints;
 use crate::{
-    error::CTraderResult,
+    error::{CTraderError, CTraderResult},
     types::{Auth, TokenResponse},
 };
+use std::time::{Duration, SystemTime};
+
+/// Number of characters of a response body kept in an error
+const BODY_EXCERPT_LEN: usize = 512;
 
 impl Auth {
     pub fn new(
@@ -13,15 +17,23 @@ impl Auth {
         refresh_token: String,
     ) -> Self {
         Self {
-            ctrader_access_token: app_access_token,
-            ctrader_refresh_token: refresh_token,
+            ctrader_access_token: app_access_token.into(),
+            ctrader_refresh_token: refresh_token.into(),
             ctrader_client_id: app_client_id,
-            ctrader_client_secret: app_client_secret,
+            ctrader_client_secret: app_client_secret.into(),
             ctrader_redirect_url: app_redirect_url,
+            ctrader_token_expires_at: None,
         }
     }
 
-    pub fn get_auth_uri(self) -> CTraderResult<String> {
+    /// Set when the access token expires, e.g. from the `expires_in` it was issued with.
+    /// The client only refreshes tokens ahead of their expiry when it is known.
+    pub fn with_token_expires_at(mut self, expires_at: SystemTime) -> Self {
+        self.ctrader_token_expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn get_auth_uri(&self) -> CTraderResult<String> {
         let auth_uri = format!(
             "{}?client_id={}&redirect_uri={}&scope=trading",
             Endpoints::AUTH_URI,
@@ -31,48 +43,315 @@ impl Auth {
         Ok(auth_uri)
     }
 
-    pub async fn get_token(self, auth_code: &str) -> CTraderResult<TokenResponse> {
-        let url = format!(
-            "{}?grant_type=authorization_code&code={}&redirect_uri={}&client_id={}&client_secret={}",
-            Endpoints::TOKEN_URI,
-            auth_code,
-            self.ctrader_redirect_url,
-            self.ctrader_client_id,
-            self.ctrader_client_secret
-        );
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await
-            .expect("unable to get access token");
+    /// Exchange the code the user was redirected with for an access and a refresh token
+    pub async fn get_token(&mut self, auth_code: &str) -> CTraderResult<TokenResponse> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", auth_code),
+            ("redirect_uri", &self.ctrader_redirect_url),
+            ("client_id", &self.ctrader_client_id),
+            ("client_secret", self.ctrader_client_secret.expose()),
+        ];
 
-        let res = response
-            .json::<TokenResponse>()
-            .await
-            .expect("error decoding json response!");
+        let res = request_token(Endpoints::TOKEN_URI, &form, "unable to get access token").await?;
+        self.update(&res);
 
         Ok(res)
     }
 
-    pub async fn refresh_token(self, refresh_token: &str) -> CTraderResult<TokenResponse> {
-        let url = format!(
-            "{}?grant_type=refresh_token&refresh_token={}&client_id={}&client_secret={}",
+    /// Exchange the refresh token for a new access and refresh token
+    pub async fn refresh_token(&mut self, refresh_token: &str) -> CTraderResult<TokenResponse> {
+        let form = [
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", &self.ctrader_client_id),
+            ("client_secret", self.ctrader_client_secret.expose()),
+        ];
+
+        let res = request_token(
             Endpoints::TOKEN_URI,
-            refresh_token,
-            self.ctrader_client_id,
-            self.ctrader_client_secret
+            &form,
+            "unable to refresh access token",
+        )
+        .await?;
+        self.update(&res);
+
+        Ok(res)
+    }
+
+    /// Replace the tokens with the ones carried by a token response
+    pub fn update(&mut self, res: &TokenResponse) {
+        if let Some(access_token) = &res.access_token {
+            self.ctrader_access_token = access_token.clone();
+        }
+
+        if let Some(refresh_token) = &res.refresh_token {
+            self.ctrader_refresh_token = refresh_token.clone();
+        }
+
+        // A lifetime too long to represent is as good as an unknown expiry
+        if let Some(expires_in) = res.expires_in {
+            self.ctrader_token_expires_at =
+                SystemTime::now().checked_add(Duration::from_secs(expires_in));
+        }
+    }
+}
+
+/// Post a form to the token endpoint and decode its response
+/// * url - The token URI
+/// * form - The form fields, kept out of the query string so secrets don't end up in logs
+/// * context - What the request was for, used in the errors returned
+async fn request_token(
+    url: &str,
+    form: &[(&str, &str)],
+    context: &str,
+) -> CTraderResult<TokenResponse> {
+    let response = reqwest::Client::new()
+        .post(url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .form(form)
+        .send()
+        .await
+        .map_err(|e| CTraderError::HttpRequest {
+            status: e.status().map(|status| status.as_u16()),
+            message: format!("{}: {}", context, e),
+            body: None,
+            error: None,
+            error_description: None,
+        })?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| http_error(Some(status.as_u16()), format!("{}: {}", context, e), ""))?;
+
+    if !status.is_success() {
+        return Err(http_error(
+            Some(status.as_u16()),
+            context.to_string(),
+            &body,
+        ));
+    }
+
+    let res: TokenResponse = serde_json::from_str(&body).map_err(|e| {
+        http_error(
+            Some(status.as_u16()),
+            format!("{}: invalid response: {}", context, e),
+            &body,
+        )
+    })?;
+
+    // CTrader reports some rejections with a successful status and the error in the body
+    if res.error.is_some() || res.access_token.is_none() {
+        return Err(http_error(
+            Some(status.as_u16()),
+            context.to_string(),
+            &body,
+        ));
+    }
+
+    Ok(res)
+}
+
+/// Build an `HttpRequest` error keeping any OAuth error fields of the body, and the start of the
+/// body for error statuses. The body of a successful response may carry tokens, so it is dropped.
+fn http_error(status: Option<u16>, message: String, body: &str) -> CTraderError {
+    let res: TokenResponse = serde_json::from_str(body).unwrap_or_default();
+    let is_success = status.is_some_and(|status| (200..300).contains(&status));
+
+    CTraderError::HttpRequest {
+        status,
+        message,
+        body: (!body.is_empty() && !is_success)
+            .then(|| body.chars().take(BODY_EXCERPT_LEN).collect()),
+        error: res.error,
+        error_description: res.error_description,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Answer a single HTTP request with `body` and return the request received
+    async fn serve_once(listener: TcpListener, body: &'static str) -> anyhow::Result<String> {
+        let (mut stream, _) = listener.accept().await?;
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+
+        // Read the headers, then as much of the body as they announce
+        loop {
+            let n = stream.read(&mut buf).await?;
+            request.extend_from_slice(&buf[..n]);
+
+            let text = String::from_utf8_lossy(&request);
+            if let Some((headers, content)) = text.split_once("\r\n\r\n") {
+                let length = headers
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .strip_prefix("content-length: ")?
+                            .parse()
+                            .ok()
+                    })
+                    .unwrap_or(0);
+
+                if n == 0 || content.len() >= length {
+                    break;
+                }
+            }
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+             content-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
         );
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await
-            .expect("unable to refresh access token");
+        stream.write_all(response.as_bytes()).await?;
 
-        let res = response
-            .json::<TokenResponse>()
-            .await
-            .expect("error decoding json response!");
+        Ok(String::from_utf8(request)?)
+    }
 
-        Ok(res)
+    #[tokio::test]
+    async fn test_token_exchange_posts_form() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/apps/token", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(serve_once(
+            listener,
+            r#"{"accessToken":"access","tokenType":"bearer","expiresIn":2628000,"refreshToken":"refresh","errorCode":null,"description":null}"#,
+        ));
+
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("client_secret", "s3cret"),
+        ];
+        let res = request_token(&url, &form, "unable to get access token").await?;
+        let request = server_handle.await??;
+
+        assert!(request.starts_with("POST /apps/token HTTP/1.1"));
+        assert!(request.ends_with("grant_type=authorization_code&client_secret=s3cret"));
+
+        let mut auth = Auth::new(
+            "id".into(),
+            "old".into(),
+            "s3cret".into(),
+            "http://localhost".into(),
+            "old".into(),
+        );
+        auth.update(&res);
+
+        assert_eq!(auth.ctrader_access_token.expose(), "access");
+        assert_eq!(auth.ctrader_refresh_token.expose(), "refresh");
+        assert_eq!(res.token_type.as_deref(), Some("bearer"));
+        assert!(auth.ctrader_token_expires_at.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_token_error_in_successful_response() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/apps/token", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(serve_once(
+            listener,
+            r#"{"errorCode":"ACCESS_DENIED","description":"Invalid code"}"#,
+        ));
+
+        let result = request_token(&url, &[], "unable to get access token").await;
+        server_handle.await??;
+
+        assert!(matches!(
+            result,
+            Err(CTraderError::HttpRequest { error: Some(error), .. }) if error == "ACCESS_DENIED"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_http_error_keeps_oauth_fields() {
+        let body = r#"{"errorCode":"ACCESS_DENIED","description":"Invalid refresh token"}"#;
+
+        match http_error(Some(400), "unable to refresh access token".into(), body) {
+            CTraderError::HttpRequest {
+                status,
+                body: excerpt,
+                error,
+                error_description,
+                ..
+            } => {
+                assert_eq!(status, Some(400));
+                assert_eq!(excerpt.as_deref(), Some(body));
+                assert_eq!(error.as_deref(), Some("ACCESS_DENIED"));
+                assert_eq!(error_description.as_deref(), Some("Invalid refresh token"));
+            }
+            other => panic!("expected an HTTP error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_http_error_truncates_body() {
+        let body = "x".repeat(BODY_EXCERPT_LEN * 2);
+
+        match http_error(Some(502), "bad gateway".into(), &body) {
+            CTraderError::HttpRequest {
+                body: Some(excerpt),
+                error: None,
+                ..
+            } => assert_eq!(excerpt.len(), BODY_EXCERPT_LEN),
+            other => panic!("expected an HTTP error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_token_expiry_can_be_set() {
+        let expires_at = SystemTime::now() + Duration::from_secs(600);
+        let auth = Auth::new(
+            "id".into(),
+            "access".into(),
+            "secret".into(),
+            "http://localhost".into(),
+            "refresh".into(),
+        )
+        .with_token_expires_at(expires_at);
+
+        assert_eq!(auth.ctrader_token_expires_at, Some(expires_at));
+    }
+
+    #[test]
+    fn test_huge_lifetime_leaves_expiry_unset() {
+        let mut auth = Auth::new(
+            "id".into(),
+            "access".into(),
+            "secret".into(),
+            "http://localhost".into(),
+            "refresh".into(),
+        )
+        .with_token_expires_at(SystemTime::now());
+
+        auth.update(&TokenResponse {
+            access_token: Some("new-access".to_string().into()),
+            expires_in: Some(u64::MAX),
+            ..Default::default()
+        });
+
+        assert_eq!(auth.ctrader_access_token.expose(), "new-access");
+        assert_eq!(auth.ctrader_token_expires_at, None);
+    }
+
+    #[test]
+    fn test_successful_body_is_not_kept() {
+        let body =
+            r#"{"accessToken":"s3cret-access","refreshToken":"s3cret-refresh","expiresIn":"x"}"#;
+        let err = http_error(Some(200), "invalid response".into(), body);
+
+        assert!(matches!(err, CTraderError::HttpRequest { body: None, .. }));
+        assert!(!format!("{:?}", err).contains("s3cret"));
     }
 }
@@ -0,0 +1,393 @@
+use super::traits::TokenStore;
+use super::{
+    DEFAULT_CONNECT_TIMEOUT, DEFAULT_EVENT_BUFFER, DEFAULT_HEARTBEAT_INTERVAL,
+    DEFAULT_READ_IDLE_TIMEOUT, DEFAULT_REQUEST_TIMEOUT, MIN_READ_IDLE_TIMEOUT,
+};
+use crate::error::{CTraderError, CTraderResult};
+use crate::types::{
+    Auth, CTraderClient, CTraderClientBuilder, Environment, ReconnectPolicy, Transport,
+};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+impl CTraderClient {
+    /// Start configuring a new client, connecting to the demo server over protobuf by default
+    pub fn builder() -> CTraderClientBuilder {
+        CTraderClientBuilder::default()
+    }
+}
+
+impl Default for CTraderClientBuilder {
+    fn default() -> Self {
+        Self {
+            environment: Environment::default(),
+            transport: Transport::default(),
+            auth: None,
+            token_store: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            read_idle_timeout: DEFAULT_READ_IDLE_TIMEOUT,
+            reconnect_policy: ReconnectPolicy::default(),
+            event_buffer: DEFAULT_EVENT_BUFFER,
+        }
+    }
+}
+
+impl CTraderClientBuilder {
+    /// Set the server to connect to
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Set the wire format spoken on the websocket
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Set the application and account credentials at once, see the setters below to set them
+    /// one by one
+    pub fn credentials(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Set the client ID of the application
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.auth_mut().ctrader_client_id = client_id.into();
+        self
+    }
+
+    /// Set the client secret of the application
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.auth_mut().ctrader_client_secret = client_secret.into().into();
+        self
+    }
+
+    /// Set the access token authorizing requests on behalf of the trading accounts
+    pub fn access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.auth_mut().ctrader_access_token = access_token.into().into();
+        self
+    }
+
+    /// Set the token used to get a new access token once it expires
+    pub fn refresh_token(mut self, refresh_token: impl Into<String>) -> Self {
+        self.auth_mut().ctrader_refresh_token = refresh_token.into().into();
+        self
+    }
+
+    /// Set the redirect URL registered for the application
+    pub fn redirect_url(mut self, redirect_url: impl Into<String>) -> Self {
+        self.auth_mut().ctrader_redirect_url = redirect_url.into();
+        self
+    }
+
+    /// Set when the access token expires, so it is refreshed ahead of time
+    pub fn token_expires_at(mut self, expires_at: SystemTime) -> Self {
+        self.auth_mut().ctrader_token_expires_at = Some(expires_at);
+        self
+    }
+
+    /// The credentials being configured, empty until a first one is set
+    fn auth_mut(&mut self) -> &mut Auth {
+        self.auth.get_or_insert_with(|| {
+            Auth::new(
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            )
+        })
+    }
+
+    /// Load the newest tokens from `store` when connecting and save rotated tokens to it
+    pub fn token_store(mut self, store: Arc<dyn TokenStore>) -> Self {
+        self.token_store = Some(store);
+        self
+    }
+
+    /// Set how long to wait for the response to a request
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Set how long a single connection attempt may take
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Set how long the connection may stay idle before a heartbeat is sent
+    pub fn heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
+    /// Set how long the server may stay silent before the connection is given up and
+    /// re-established, at least 10s and longer than the heartbeat interval
+    pub fn read_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.read_idle_timeout = timeout;
+        self
+    }
+
+    /// Set how often and how fast to retry a failed or lost connection
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
+    /// Set the number of events buffered for each subscriber before it starts lagging
+    pub fn event_buffer(mut self, size: usize) -> Self {
+        self.event_buffer = size;
+        self
+    }
+
+    /// Connect to the configured server and spawn the background tasks owned by the client
+    pub async fn connect(mut self) -> CTraderResult<CTraderClient> {
+        let stored = match &self.token_store {
+            Some(store) => store.load().await?,
+            None => None,
+        };
+
+        // Stored tokens were rotated after the credentials were configured, so they win
+        let auth = match (self.auth.take(), stored) {
+            (Some(mut auth), Some(stored)) => {
+                auth.ctrader_access_token = stored.ctrader_access_token.clone();
+                auth.ctrader_refresh_token = stored.ctrader_refresh_token.clone();
+                auth.ctrader_token_expires_at = stored.ctrader_token_expires_at;
+                auth
+            }
+            (Some(auth), None) | (None, Some(auth)) => auth,
+            (None, None) => {
+                return Err(CTraderError::Other(
+                    "no credentials were given to the client builder".into(),
+                ));
+            }
+        };
+
+        if auth.ctrader_client_id.is_empty() || auth.ctrader_client_secret.expose().is_empty() {
+            return Err(CTraderError::Other(
+                "the client ID and secret of the application are required".into(),
+            ));
+        }
+
+        if self.event_buffer == 0 {
+            return Err(CTraderError::Other(
+                "the event buffer must hold at least one event".into(),
+            ));
+        }
+
+        if self.heartbeat_interval.is_zero() {
+            return Err(CTraderError::Other(
+                "the heartbeat interval must be longer than zero".into(),
+            ));
+        }
+
+        if self.request_timeout.is_zero() {
+            return Err(CTraderError::Other(
+                "the request timeout must be longer than zero".into(),
+            ));
+        }
+
+        if self.read_idle_timeout < MIN_READ_IDLE_TIMEOUT {
+            return Err(CTraderError::Other(format!(
+                "the read idle timeout must be at least {:?}, got {:?}",
+                MIN_READ_IDLE_TIMEOUT, self.read_idle_timeout
+            )));
+        }
+
+        // A quiet connection only sees our heartbeats answered, so it must outlast them
+        if self.read_idle_timeout <= self.heartbeat_interval {
+            return Err(CTraderError::Other(format!(
+                "the read idle timeout of {:?} must be longer than the heartbeat interval of {:?}",
+                self.read_idle_timeout, self.heartbeat_interval
+            )));
+        }
+
+        let policy = &self.reconnect_policy;
+        if !policy.multiplier.is_finite() || policy.multiplier < 1.0 {
+            return Err(CTraderError::Other(format!(
+                "the reconnect backoff multiplier must be a finite number of at least 1, got {}",
+                policy.multiplier
+            )));
+        }
+
+        if policy.initial_backoff > policy.max_backoff {
+            return Err(CTraderError::Other(
+                "the initial reconnect backoff must not exceed the maximum backoff".into(),
+            ));
+        }
+
+        if policy.max_attempts == Some(0) {
+            return Err(CTraderError::Other(
+                "the reconnect policy must allow at least one attempt".into(),
+            ));
+        }
+
+        CTraderClient::connect(self, auth).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    #[tokio::test]
+    async fn test_connect_to_custom_environment() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            anyhow::Ok(accept_async(stream).await?)
+        });
+
+        let auth = Auth::new(
+            "id".into(),
+            "access".into(),
+            "secret".into(),
+            "http://localhost".into(),
+            "refresh".into(),
+        );
+
+        let client = CTraderClient::builder()
+            .environment(Environment::Custom { url })
+            .transport(Transport::Json)
+            .credentials(auth)
+            .request_timeout(Duration::from_secs(1))
+            .heartbeat_interval(Duration::from_secs(5))
+            .event_buffer(16)
+            .connect()
+            .await?;
+
+        let _server_stream = server_handle.await??;
+
+        assert_eq!(client.transport, Transport::Json);
+        assert_eq!(client.request_timeout, Duration::from_secs(1));
+        assert_eq!(client.tasks.lock().unwrap().len(), 3);
+
+        for task in client.tasks.lock().unwrap().iter() {
+            task.abort();
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_connect_requires_credentials() {
+        assert!(CTraderClient::builder().connect().await.is_err());
+        assert!(
+            CTraderClient::builder()
+                .access_token("access")
+                .connect()
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_invalid_multiplier() {
+        for multiplier in [0.5, -2.0, f64::NAN, f64::INFINITY] {
+            let result = CTraderClient::builder()
+                .client_id("id")
+                .client_secret("secret")
+                .reconnect_policy(ReconnectPolicy {
+                    multiplier,
+                    ..Default::default()
+                })
+                .connect()
+                .await;
+
+            assert!(
+                matches!(result, Err(CTraderError::Other(_))),
+                "multiplier {}",
+                multiplier
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_invalid_settings() {
+        let builder = CTraderClient::builder()
+            .client_id("id")
+            .client_secret("secret");
+
+        let invalid = [
+            builder.clone().event_buffer(0),
+            builder.clone().heartbeat_interval(Duration::ZERO),
+            builder.clone().request_timeout(Duration::ZERO),
+            builder.clone().reconnect_policy(ReconnectPolicy {
+                initial_backoff: Duration::from_secs(60),
+                max_backoff: Duration::from_secs(1),
+                ..Default::default()
+            }),
+            builder.reconnect_policy(ReconnectPolicy {
+                max_attempts: Some(0),
+                ..Default::default()
+            }),
+        ];
+
+        for builder in invalid {
+            let result = builder.connect().await;
+            assert!(matches!(result, Err(CTraderError::Other(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_short_read_idle_timeout() {
+        let result = CTraderClient::builder()
+            .client_id("id")
+            .client_secret("secret")
+            .read_idle_timeout(Duration::from_millis(1))
+            .connect()
+            .await;
+
+        assert!(matches!(result, Err(CTraderError::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_read_idle_timeout_within_heartbeat_interval() {
+        for read_idle_timeout in [Duration::from_secs(20), Duration::from_secs(15)] {
+            let result = CTraderClient::builder()
+                .client_id("id")
+                .client_secret("secret")
+                .heartbeat_interval(Duration::from_secs(20))
+                .read_idle_timeout(read_idle_timeout)
+                .connect()
+                .await;
+
+            assert!(
+                matches!(result, Err(CTraderError::Other(_))),
+                "read idle timeout {:?}",
+                read_idle_timeout
+            );
+        }
+    }
+
+    #[test]
+    fn test_named_credentials() {
+        let expires_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let builder = CTraderClient::builder()
+            .client_id("id")
+            .client_secret("secret")
+            .access_token("access")
+            .refresh_token("refresh")
+            .redirect_url("http://localhost")
+            .token_expires_at(expires_at);
+
+        let auth = builder.auth.expect("credentials");
+
+        assert_eq!(auth.ctrader_client_id, "id");
+        assert_eq!(auth.ctrader_client_secret.expose(), "secret");
+        assert_eq!(auth.ctrader_access_token.expose(), "access");
+        assert_eq!(auth.ctrader_refresh_token.expose(), "refresh");
+        assert_eq!(auth.ctrader_redirect_url, "http://localhost");
+        assert_eq!(auth.ctrader_token_expires_at, Some(expires_at));
+    }
+}
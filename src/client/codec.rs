@@ -0,0 +1,334 @@
+use super::traits::Payload;
+use crate::error::{CTraderError, CTraderResult};
+use crate::openapi::ProtoMessage;
+use crate::types::Transport;
+use prost::Message;
+use serde::{Serialize, de::DeserializeOwned};
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+/// An envelope received from the server, independent of the transport it arrived on
+/// * payload_type - The payload type id of the carried message
+/// * client_msg_id - The id of the request this message answers, if any
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub payload_type: u32,
+    pub client_msg_id: Option<String>,
+    body: Body,
+}
+
+/// The still encoded message carried by a `Frame`
+#[derive(Debug, Clone)]
+enum Body {
+    Protobuf(Vec<u8>),
+    Json(serde_json::Value),
+}
+
+impl Frame {
+    /// Decode the carried message into its typed form.
+    /// JSON errors leave out the offending values, which may be credentials.
+    pub fn payload<M: Message + Default + DeserializeOwned>(&self) -> CTraderResult<M> {
+        match &self.body {
+            Body::Protobuf(payload) => Ok(M::decode(payload.as_slice())?),
+            Body::Json(serde_json::Value::Null) => Ok(M::default()),
+            Body::Json(payload) => M::deserialize(payload).map_err(|_| {
+                CTraderError::Other(format!(
+                    "JSON payload does not match payload type {}",
+                    self.payload_type
+                ))
+            }),
+        }
+    }
+}
+
+impl Transport {
+    /// Wrap a message in an envelope using the wire format of this transport.
+    pub fn encode<M: Payload + Serialize>(
+        &self,
+        message: &M,
+        client_msg_id: Option<String>,
+    ) -> CTraderResult<WsMessage> {
+        match self {
+            Transport::Protobuf => Ok(encode(message, client_msg_id)),
+            Transport::Json => encode_json(message, client_msg_id),
+        }
+    }
+}
+
+/// Wrap a message in a `ProtoMessage` envelope and return it as a websocket binary frame.
+/// * message - The message to wrap
+/// * client_msg_id - Optional id echoed back by the server in the response
+pub fn encode<M: Payload>(message: &M, client_msg_id: Option<String>) -> WsMessage {
+    let envelope = ProtoMessage {
+        payload_type: M::PAYLOAD_TYPE,
+        payload: Some(message.encode_to_vec()),
+        client_msg_id,
+    };
+
+    WsMessage::Binary(envelope.encode_to_vec().into())
+}
+
+/// Wrap a message in a JSON envelope and return it as a websocket text frame.
+/// Optional fields that are not set are left out rather than sent as `null`.
+/// * message - The message to wrap
+/// * client_msg_id - Optional id echoed back by the server in the response
+pub fn encode_json<M: Payload + Serialize>(
+    message: &M,
+    client_msg_id: Option<String>,
+) -> CTraderResult<WsMessage> {
+    let mut payload = serde_json::to_value(message)?;
+    strip_nulls(&mut payload);
+
+    let mut envelope = serde_json::json!({
+        "payloadType": M::PAYLOAD_TYPE,
+        "payload": payload,
+    });
+
+    if let Some(client_msg_id) = client_msg_id {
+        envelope["clientMsgId"] = client_msg_id.into();
+    }
+
+    Ok(WsMessage::Text(serde_json::to_string(&envelope)?.into()))
+}
+
+/// Remove the `null` fields of every object in a JSON value, the server rejects them
+fn strip_nulls(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            fields.retain(|_, field| !field.is_null());
+            fields.values_mut().for_each(strip_nulls);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+/// Decode a websocket binary frame holding a `ProtoMessage` envelope.
+pub fn decode(data: &[u8]) -> CTraderResult<Frame> {
+    let envelope = ProtoMessage::decode(data)?;
+
+    Ok(Frame {
+        payload_type: envelope.payload_type,
+        client_msg_id: envelope.client_msg_id,
+        body: Body::Protobuf(envelope.payload.unwrap_or_default()),
+    })
+}
+
+/// Decode a websocket text frame holding a JSON envelope.
+/// The text may carry credentials, so errors only mention its length.
+pub fn decode_json(text: &str) -> CTraderResult<Frame> {
+    let mut envelope: serde_json::Value = serde_json::from_str(text)?;
+
+    let payload_type = envelope["payloadType"]
+        .as_u64()
+        .and_then(|payload_type| u32::try_from(payload_type).ok())
+        .ok_or_else(|| {
+            CTraderError::Other(format!(
+                "JSON message of {} bytes without payloadType",
+                text.len()
+            ))
+        })?;
+
+    let client_msg_id = envelope["clientMsgId"].as_str().map(str::to_string);
+
+    Ok(Frame {
+        payload_type,
+        client_msg_id,
+        body: Body::Json(envelope["payload"].take()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::{
+        ProtoOaApplicationAuthReq, ProtoOaNewOrderReq, ProtoOaRefreshTokenRes, ProtoOaTraderReq,
+        ProtoOaTraderRes,
+    };
+
+    /// `ProtoOaApplicationAuthReq { client_id: "id", client_secret: "secret" }` wrapped
+    /// in an envelope with payload type 2100 and client message id "1".
+    const APPLICATION_AUTH_FRAME: &[u8] = &[
+        0x08, 0xB4, 0x10, // payloadType = 2100
+        0x12, 0x0F, // payload, 15 bytes
+        0x08, 0xB4, 0x10, // payloadType = 2100
+        0x12, 0x02, b'i', b'd', // clientId = "id"
+        0x1A, 0x06, b's', b'e', b'c', b'r', b'e', b't', // clientSecret = "secret"
+        0x1A, 0x01, b'1', // clientMsgId = "1"
+    ];
+
+    #[test]
+    fn test_encode_application_auth_req() {
+        let req = ProtoOaApplicationAuthReq {
+            client_id: "id".into(),
+            client_secret: "secret".into(),
+            payload_type: Some(2100),
+        };
+
+        let frame = encode(&req, Some("1".into()));
+
+        assert_eq!(
+            frame,
+            WsMessage::Binary(APPLICATION_AUTH_FRAME.to_vec().into())
+        );
+    }
+
+    #[test]
+    fn test_decode_application_auth_req() -> anyhow::Result<()> {
+        let frame = decode(APPLICATION_AUTH_FRAME)?;
+
+        assert_eq!(frame.payload_type, 2100);
+        assert_eq!(frame.client_msg_id.as_deref(), Some("1"));
+
+        let req: ProtoOaApplicationAuthReq = frame.payload()?;
+
+        assert_eq!(req.client_id, "id");
+        assert_eq!(req.client_secret, "secret");
+        assert_eq!(req.payload_type, Some(2100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_without_client_msg_id() {
+        let req = ProtoOaTraderReq {
+            ctid_trader_account_id: 42,
+            payload_type: Some(2121),
+        };
+
+        let frame = encode(&req, None);
+
+        let expected: &[u8] = &[
+            0x08, 0xC9, 0x10, // payloadType = 2121
+            0x12, 0x05, // payload, 5 bytes
+            0x08, 0xC9, 0x10, // payloadType = 2121
+            0x10, 0x2A, // ctidTraderAccountId = 42
+        ];
+
+        assert_eq!(frame, WsMessage::Binary(expected.to_vec().into()));
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert!(decode(&[0xFF, 0xFF, 0xFF]).is_err());
+        assert!(decode_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_json_payload_error_leaves_out_the_values() -> anyhow::Result<()> {
+        let frame = decode_json(
+            r#"{"payloadType":2174,"payload":{"accessToken":"s3cret-access","expiresIn":"s3cret"}}"#,
+        )?;
+        let err = frame
+            .payload::<ProtoOaRefreshTokenRes>()
+            .expect_err("expiresIn is not a number");
+
+        assert!(!err.to_string().contains("s3cret"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_json_error_leaves_out_the_text() {
+        let text = r#"{"payload":{"accessToken":"s3cret-access"}}"#;
+        let err = decode_json(text).expect_err("a frame without payloadType");
+
+        assert!(!err.to_string().contains("s3cret"));
+        assert!(err.to_string().contains(&text.len().to_string()));
+    }
+
+    #[test]
+    fn test_encode_json_application_auth_req() -> anyhow::Result<()> {
+        let req = ProtoOaApplicationAuthReq {
+            client_id: "id".into(),
+            client_secret: "secret".into(),
+            payload_type: Some(2100),
+        };
+
+        let frame = encode_json(&req, Some("1".into()))?;
+        let WsMessage::Text(text) = frame else {
+            panic!("expected a text frame, got {:?}", frame);
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&text)?;
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "clientMsgId": "1",
+                "payloadType": 2100,
+                "payload": {
+                    "payloadType": 2100,
+                    "clientId": "id",
+                    "clientSecret": "secret",
+                },
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_json_skips_unset_optionals() -> anyhow::Result<()> {
+        let req = ProtoOaNewOrderReq {
+            ctid_trader_account_id: 42,
+            symbol_id: 1,
+            order_type: 1,
+            trade_side: 1,
+            volume: 1000,
+            ..Default::default()
+        };
+
+        let frame = encode_json(&req, None)?;
+        let WsMessage::Text(text) = frame else {
+            panic!("expected a text frame, got {:?}", frame);
+        };
+
+        assert!(!text.contains("null"), "{}", text);
+
+        let json: serde_json::Value = serde_json::from_str(&text)?;
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "payloadType": 2106,
+                "payload": {
+                    "ctidTraderAccountId": 42,
+                    "symbolId": 1,
+                    "orderType": 1,
+                    "tradeSide": 1,
+                    "volume": 1000,
+                },
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_json_trader_res() -> anyhow::Result<()> {
+        let text = r#"{
+            "clientMsgId": "7",
+            "payloadType": 2122,
+            "payload": {
+                "ctidTraderAccountId": 42,
+                "trader": {
+                    "ctidTraderAccountId": 42,
+                    "balance": 1000000,
+                    "depositAssetId": 3
+                }
+            }
+        }"#;
+
+        let frame = decode_json(text)?;
+
+        assert_eq!(frame.payload_type, 2122);
+        assert_eq!(frame.client_msg_id.as_deref(), Some("7"));
+
+        let res: ProtoOaTraderRes = frame.payload()?;
+
+        assert_eq!(res.ctid_trader_account_id, 42);
+        assert_eq!(res.trader.balance, 1_000_000);
+
+        Ok(())
+    }
+}
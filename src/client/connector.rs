@@ -1,7 +1,12 @@
+use super::random::random_unit;
+use super::shutdown::stopped;
 use super::traits::AppState;
-use crate::error::ConnectorResult;
+use crate::error::{ConnectorError, ConnectorResult};
+use crate::types::ReconnectPolicy;
+use std::time::Duration;
 use tokio::net::TcpStream;
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tokio::sync::watch;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite};
 
 pub type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
@@ -20,3 +25,195 @@ pub trait Connector<S: AppState>: Send + Sync {
         self.connect(state).await
     }
 }
+
+/// Connects to the CTrader OpenAPI websocket, retrying with exponential backoff and jitter
+/// * url - The websocket URL to connect to
+/// * policy - How often and how fast to retry a failed connection attempt
+/// * timeout - How long a single connection attempt may take
+/// * shutdown - Stops retrying once it holds `true`
+#[derive(Debug, Clone)]
+pub struct WsConnector {
+    pub url: String,
+    pub policy: ReconnectPolicy,
+    pub timeout: Duration,
+    pub shutdown: watch::Receiver<bool>,
+}
+
+impl WsConnector {
+    pub fn new(
+        url: String,
+        policy: ReconnectPolicy,
+        timeout: Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            url,
+            policy,
+            timeout,
+            shutdown,
+        }
+    }
+
+    /// The error returned once the client shut down while connecting
+    fn stopped(&self) -> ConnectorError {
+        tracing::info!("Stopped connecting to {}, the client shut down", self.url);
+
+        ConnectorError::Stopped {
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay before the given retry attempt (starting at 1), with up to half of it randomized
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16) as i32;
+        let max_backoff = self.max_backoff.as_secs_f64();
+
+        // Work in seconds so a huge multiplier caps at `max_backoff` instead of overflowing
+        let delay =
+            (self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent)).min(max_backoff);
+
+        // Randomize the lower half of the delay so many clients don't retry in lockstep
+        let jitter = random_unit();
+
+        Duration::try_from_secs_f64(delay * (0.5 + jitter * 0.5)).unwrap_or(self.max_backoff)
+    }
+}
+
+impl<S: AppState> Connector<S> for WsConnector {
+    async fn connect(&self, state: S) -> ConnectorResult<WsStream> {
+        let mut attempt = 0;
+        let mut shutdown = self.shutdown.clone();
+
+        if !state.is_empty() {
+            tracing::info!(
+                "Reconnecting with {} authorized accounts to restore",
+                state.authorized_accounts().len()
+            );
+        }
+
+        loop {
+            attempt += 1;
+
+            tracing::info!(
+                "Connecting Async Client to CTrader Endpoint {} OpenAPI (attempt {})",
+                self.url,
+                attempt
+            );
+
+            let connecting = tokio::time::timeout(self.timeout, connect_async(self.url.as_str()));
+            let result = tokio::select! {
+                result = connecting => result,
+                _ = stopped(&mut shutdown) => return Err(self.stopped()),
+            };
+
+            let err = match result {
+                Ok(Ok((ws_stream, _))) => {
+                    tracing::info!("Successfully Connected Async Client to CTrader OpenAPI");
+                    return Ok(ws_stream);
+                }
+                Ok(Err(err)) => err,
+                Err(_) => tungstenite::Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("connection attempt timed out after {:?}", self.timeout),
+                )),
+            };
+
+            if self
+                .policy
+                .max_attempts
+                .is_some_and(|max_attempts| attempt >= max_attempts)
+            {
+                return Err(ConnectorError::RetriesExhausted {
+                    url: self.url.clone(),
+                    attempts: attempt,
+                    source: Box::new(err),
+                });
+            }
+
+            let delay = self.policy.backoff(attempt);
+
+            tracing::warn!(
+                "Error connecting to {}: {} retrying in {:?}",
+                self.url,
+                err,
+                delay
+            );
+
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = stopped(&mut shutdown) => return Err(self.stopped()),
+            }
+        }
+    }
+
+    async fn disconnect(&self) -> ConnectorResult<()> {
+        // The websocket is owned by the client, closing it is all there is to do
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Session;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            max_attempts: None,
+        };
+
+        for (attempt, expected) in [(1, 1), (2, 2), (3, 4), (4, 8), (5, 10), (30, 10)] {
+            let delay = policy.backoff(attempt);
+            let expected = Duration::from_secs(expected);
+
+            assert!(delay <= expected, "attempt {}: {:?}", attempt, delay);
+            assert!(delay >= expected / 2, "attempt {}: {:?}", attempt, delay);
+        }
+    }
+
+    #[test]
+    fn test_backoff_does_not_overflow() {
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::MAX,
+            max_backoff: Duration::from_secs(10),
+            multiplier: f64::MAX,
+            max_attempts: None,
+        };
+
+        for attempt in [1, 2, 17, u32::MAX] {
+            assert!(policy.backoff(attempt) <= Duration::from_secs(10));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_stops_retrying() -> anyhow::Result<()> {
+        // Nothing listens on the port once the listener is dropped
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+        drop(listener);
+
+        let (shutdown, receiver) = watch::channel(false);
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_secs(60),
+            max_backoff: Duration::from_secs(60),
+            ..Default::default()
+        };
+        let connector = WsConnector::new(url, policy, Duration::from_secs(5), receiver);
+
+        let connecting = tokio::spawn(async move { connector.connect(Session::default()).await });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        shutdown.send_replace(true);
+
+        let result = tokio::time::timeout(Duration::from_secs(5), connecting).await??;
+
+        assert!(matches!(result, Err(ConnectorError::Stopped { .. })));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use crate::error::CTraderResult;
+use crate::openapi::{ProtoOaCtidProfile, ProtoOaCtidTraderAccount};
+use crate::types::{AccountSession, CTraderClient, Environment, TraderAccount};
+
+impl From<ProtoOaCtidTraderAccount> for TraderAccount {
+    fn from(account: ProtoOaCtidTraderAccount) -> Self {
+        Self {
+            account_id: account.ctid_trader_account_id as i64,
+            is_live: account.is_live.unwrap_or_default(),
+            broker_title: account.broker_title_short,
+            trader_login: account.trader_login,
+        }
+    }
+}
+
+impl Environment {
+    /// Whether accounts of the given kind can be authorized on this environment.
+    /// Live accounts are only served by the live host and demo accounts by the demo host,
+    /// a custom URL is assumed to serve both.
+    pub fn serves(&self, account: &TraderAccount) -> bool {
+        match self {
+            Environment::Demo => !account.is_live,
+            Environment::Live => account.is_live,
+            Environment::Custom { .. } => true,
+        }
+    }
+}
+
+impl CTraderClient {
+    /// List the trading accounts the access token grants access to, live and demo alike
+    pub async fn accounts(&self) -> CTraderResult<Vec<TraderAccount>> {
+        let res = self.send_get_account_list_by_access_token_request().await?;
+
+        Ok(res
+            .ctid_trader_account
+            .into_iter()
+            .map(TraderAccount::from)
+            .collect())
+    }
+
+    /// Authorize every account of the access token that the connected environment serves
+    pub async fn authorize_accounts(&self) -> CTraderResult<Vec<AccountSession>> {
+        self.authorize_accounts_matching(|_| true).await
+    }
+
+    /// Authorize the accounts of the access token picked by `filter`, skipping the ones the
+    /// connected environment does not serve, e.g. live accounts on the demo host.
+    /// * filter - Returns `true` for the accounts to authorize
+    pub async fn authorize_accounts_matching<F>(
+        &self,
+        filter: F,
+    ) -> CTraderResult<Vec<AccountSession>>
+    where
+        F: Fn(&TraderAccount) -> bool,
+    {
+        let mut sessions = Vec::new();
+
+        for account in self.accounts().await? {
+            if self.environment.serves(&account) && filter(&account) {
+                sessions.push(self.account(account.account_id).await?);
+            }
+        }
+
+        Ok(sessions)
+    }
+
+    /// Fetch the cTID profile of the user the access token was issued to
+    pub async fn profile(&self) -> CTraderResult<ProtoOaCtidProfile> {
+        Ok(self.send_get_ctid_profile_by_token_request().await?.profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::codec;
+    use crate::client::mock::{connect, mock_server, next_request};
+    use crate::client::traits::Payload;
+    use crate::openapi::{
+        ProtoOaAccountAuthReq, ProtoOaAccountAuthRes, ProtoOaAccountLogoutReq,
+        ProtoOaAccountLogoutRes, ProtoOaGetAccountListByAccessTokenRes,
+        ProtoOaGetCtidProfileByTokenReq, ProtoOaGetCtidProfileByTokenRes,
+    };
+    use futures_util::SinkExt;
+    use std::time::Duration;
+
+    fn trader_account(account_id: u64, is_live: bool, broker: &str) -> ProtoOaCtidTraderAccount {
+        ProtoOaCtidTraderAccount {
+            ctid_trader_account_id: account_id,
+            is_live: Some(is_live),
+            trader_login: Some(account_id as i64 + 1000),
+            broker_title_short: Some(broker.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_environment_serves_accounts() {
+        let live: TraderAccount = trader_account(1, true, "A").into();
+        let demo: TraderAccount = trader_account(2, false, "A").into();
+        let custom = Environment::Custom {
+            url: "ws://127.0.0.1:9000".into(),
+        };
+
+        assert_eq!(live.account_id, 1);
+        assert_eq!(live.trader_login, Some(1001));
+        assert!(Environment::Live.serves(&live) && !Environment::Live.serves(&demo));
+        assert!(Environment::Demo.serves(&demo) && !Environment::Demo.serves(&live));
+        assert!(custom.serves(&live) && custom.serves(&demo));
+    }
+
+    #[tokio::test]
+    async fn test_authorize_accounts_matching() -> anyhow::Result<()> {
+        // List three accounts, authorize and log out any account and answer the profile request
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            let mut authorized = Vec::new();
+            let mut logged_out = Vec::new();
+
+            while let Some(frame) = next_request(&mut ws_stream).await? {
+                let id = frame.client_msg_id.clone();
+
+                let reply = match frame.payload_type {
+                    ProtoOaAccountAuthReq::PAYLOAD_TYPE => {
+                        let req: ProtoOaAccountAuthReq = frame.payload()?;
+                        authorized.push(req.ctid_trader_account_id);
+
+                        let res = ProtoOaAccountAuthRes {
+                            ctid_trader_account_id: req.ctid_trader_account_id,
+                            ..Default::default()
+                        };
+                        codec::encode(&res, id)
+                    }
+                    ProtoOaAccountLogoutReq::PAYLOAD_TYPE => {
+                        let req: ProtoOaAccountLogoutReq = frame.payload()?;
+                        logged_out.push(req.ctid_trader_account_id);
+
+                        let res = ProtoOaAccountLogoutRes {
+                            ctid_trader_account_id: req.ctid_trader_account_id,
+                            ..Default::default()
+                        };
+                        codec::encode(&res, id)
+                    }
+                    ProtoOaGetCtidProfileByTokenReq::PAYLOAD_TYPE => {
+                        let mut res = ProtoOaGetCtidProfileByTokenRes::default();
+                        res.profile.user_id = 42;
+                        codec::encode(&res, id)
+                    }
+                    _ => {
+                        let res = ProtoOaGetAccountListByAccessTokenRes {
+                            ctid_trader_account: vec![
+                                trader_account(1, true, "A"),
+                                trader_account(2, false, "A"),
+                                trader_account(3, false, "B"),
+                            ],
+                            ..Default::default()
+                        };
+                        codec::encode(&res, id)
+                    }
+                };
+
+                ws_stream.send(reply).await?;
+            }
+
+            anyhow::Ok((authorized, logged_out))
+        })
+        .await?;
+
+        let client = connect(url).await?;
+
+        let accounts = client.accounts().await?;
+        assert_eq!(accounts.len(), 3);
+        assert_eq!(accounts[2].broker_title.as_deref(), Some("B"));
+
+        let sessions = client
+            .authorize_accounts_matching(|account| !account.is_live)
+            .await?;
+        let ids: Vec<i64> = sessions.iter().map(AccountSession::account_id).collect();
+        assert_eq!(ids, vec![2, 3]);
+
+        assert_eq!(client.profile().await?.user_id, 42);
+
+        // Accounts 2 and 3 are already authorized, only account 1 is left
+        client.authorize_accounts().await?;
+
+        client.shutdown(Duration::from_secs(5)).await?;
+
+        let (authorized, logged_out) = server_handle.await??;
+        assert_eq!(authorized, vec![2, 3, 1]);
+        assert_eq!(logged_out, vec![1, 2, 3]);
+
+        Ok(())
+    }
+}
@@ -1,11 +1,14 @@
+use crate::types::{Environment, Transport};
+
 /// CTrader endpoints
 /// * AUTH_URI - The CTrader authorization URI (<https://openapi.ctrader.com/apps/auth>)
 /// * TOKEN_URI - The CTrader tocken aquisition URI (<https://openapi.ctrader.com/apps/token>)
 /// * DEMO_HOST_URI - The CTrader Demo Host HTTP URI (<https://demo.ctraderapi.com>)
 /// * DEMO_HOST_WS_URI - The CTrader Demo Host HTTPS URI (<wss://demo.ctraderapi.com>)
 /// * LIVE_HOST_WS_URI - The CTrader Live Host WSS URI (<wss://live.ctraderapi.com>)
-/// * LIVE_HOST_URI - The CTrader Live Host HTTPS URI (<https://demo.ctraderapi.com>)
+/// * LIVE_HOST_URI - The CTrader Live Host HTTPS URI (<https://live.ctraderapi.com>)
 /// * JSON_PORT - The CTrader Port with support for JSON (5036)
+/// * PROTOBUF_PORT - The CTrader Port with support for Protobuf (5035)
 pub struct Endpoints;
 
 #[allow(dead_code)]
@@ -19,3 +22,47 @@ impl Endpoints {
     pub const JSON_PORT: usize = 5036;
     pub const PROTOBUF_PORT: usize = 5035;
 }
+
+impl Transport {
+    /// The port serving this transport
+    pub fn port(&self) -> usize {
+        match self {
+            Transport::Protobuf => Endpoints::PROTOBUF_PORT,
+            Transport::Json => Endpoints::JSON_PORT,
+        }
+    }
+}
+
+impl Environment {
+    /// The websocket URL serving the given transport in this environment.
+    /// Custom URLs are used as is and must carry their own port.
+    pub fn url(&self, transport: Transport) -> String {
+        match self {
+            Environment::Demo => format!("{}:{}", Endpoints::DEMO_HOST_WS_URI, transport.port()),
+            Environment::Live => format!("{}:{}", Endpoints::LIVE_HOST_WS_URI, transport.port()),
+            Environment::Custom { url } => url.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_url() {
+        assert_eq!(
+            Environment::Demo.url(Transport::Protobuf),
+            "wss://demo.ctraderapi.com:5035"
+        );
+        assert_eq!(
+            Environment::Live.url(Transport::Json),
+            "wss://live.ctraderapi.com:5036"
+        );
+
+        let custom = Environment::Custom {
+            url: "ws://127.0.0.1:9000".into(),
+        };
+        assert_eq!(custom.url(Transport::Protobuf), "ws://127.0.0.1:9000");
+    }
+}
@@ -0,0 +1,163 @@
+use crate::error::Lagged;
+use crate::openapi::{ProtoOaDepthEvent, ProtoOaExecutionEvent, ProtoOaSpotEvent};
+use crate::types::{CTraderClient, Event};
+use futures_util::stream::{self, Stream, StreamExt};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// Turn a broadcast receiver into a stream, reporting skipped events as `Event::Lagged`
+fn into_stream(receiver: broadcast::Receiver<Event>) -> impl Stream<Item = Event> + Send + 'static {
+    stream::unfold(receiver, |mut receiver| async move {
+        match receiver.recv().await {
+            Ok(event) => Some((event, receiver)),
+            Err(RecvError::Lagged(skipped)) => Some((Event::Lagged { skipped }, receiver)),
+            Err(RecvError::Closed) => None,
+        }
+    })
+}
+
+impl CTraderClient {
+    /// Stream every event pushed by the CTrader OpenAPI.
+    /// Each call returns an independent stream; a consumer that falls more than the event
+    /// buffer behind receives `Event::Lagged` with the number of events it missed.
+    pub fn events(&self) -> impl Stream<Item = Event> + Send + 'static {
+        into_stream(self.subscribe())
+    }
+
+    /// Stream the spot events of a symbol subscribed by a trading account, or `Lagged` when
+    /// the stream falls behind. Symbol ids are only unique within the broker of the account.
+    pub fn spots(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+    ) -> impl Stream<Item = Result<ProtoOaSpotEvent, Lagged>> + Send + 'static {
+        filter_events(self.events(), move |event| match event {
+            Event::Spot(spot)
+                if spot.ctid_trader_account_id == account_id && spot.symbol_id == symbol_id =>
+            {
+                Some(spot)
+            }
+            _ => None,
+        })
+    }
+
+    /// Stream the depth of market events of a symbol subscribed by a trading account, or
+    /// `Lagged` when the stream falls behind
+    pub fn depth(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+    ) -> impl Stream<Item = Result<ProtoOaDepthEvent, Lagged>> + Send + 'static {
+        filter_events(self.events(), move |event| match event {
+            Event::Depth(depth)
+                if depth.ctid_trader_account_id == account_id
+                    && depth.symbol_id == symbol_id as u64 =>
+            {
+                Some(depth)
+            }
+            _ => None,
+        })
+    }
+
+    /// Stream the execution events of a single trading account, or `Lagged` when the stream
+    /// falls behind and may have missed some of them
+    pub fn executions(
+        &self,
+        account_id: i64,
+    ) -> impl Stream<Item = Result<ProtoOaExecutionEvent, Lagged>> + Send + 'static {
+        filter_events(self.events(), move |event| match event {
+            Event::Execution(execution) if execution.ctid_trader_account_id == account_id => {
+                Some(execution)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Keep the events picked by `pick`, passing `Event::Lagged` through as an error
+fn filter_events<T, F>(
+    events: impl Stream<Item = Event> + Send + 'static,
+    pick: F,
+) -> impl Stream<Item = Result<T, Lagged>> + Send + 'static
+where
+    T: Send + 'static,
+    F: Fn(Event) -> Option<T> + Send + Sync + Copy + 'static,
+{
+    events.filter_map(move |event| async move {
+        match event {
+            Event::Lagged { skipped } => Some(Err(Lagged { skipped })),
+            event => pick(event).map(Ok),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock;
+    use anyhow::Context;
+
+    #[tokio::test]
+    async fn test_stream_reports_lag() {
+        let (sender, receiver) = broadcast::channel(2);
+        let mut events = Box::pin(into_stream(receiver));
+
+        for _ in 0..3 {
+            sender.send(Event::Heartbeat).unwrap();
+        }
+        drop(sender);
+
+        assert!(matches!(
+            events.next().await,
+            Some(Event::Lagged { skipped: 1 })
+        ));
+        assert!(matches!(events.next().await, Some(Event::Heartbeat)));
+        assert!(matches!(events.next().await, Some(Event::Heartbeat)));
+        assert!(events.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spots_are_filtered_by_account() -> anyhow::Result<()> {
+        let (url, _server_handle) =
+            mock::mock_server(
+                |mut ws_stream| async move { mock::next_request(&mut ws_stream).await },
+            )
+            .await?;
+        let client = mock::connect(url).await?;
+        let mut spots = Box::pin(client.spots(7, 41));
+
+        // The same symbol id names another symbol at the broker of account 8
+        for account_id in [8, 7] {
+            client.events.send(Event::Spot(ProtoOaSpotEvent {
+                ctid_trader_account_id: account_id,
+                symbol_id: 41,
+                ..Default::default()
+            }))?;
+        }
+
+        let spot = spots.next().await.context("the stream ended")??;
+        assert_eq!(spot.ctid_trader_account_id, 7);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filtered_stream_reports_lag() {
+        let (sender, receiver) = broadcast::channel(2);
+        let mut executions = Box::pin(filter_events(into_stream(receiver), |event| match event {
+            Event::Execution(execution) => Some(execution),
+            _ => None,
+        }));
+
+        for _ in 0..2 {
+            sender.send(Event::Heartbeat).unwrap();
+        }
+        sender
+            .send(Event::Execution(ProtoOaExecutionEvent::default()))
+            .unwrap();
+        drop(sender);
+
+        assert_eq!(executions.next().await, Some(Err(Lagged { skipped: 1 })));
+        assert!(matches!(executions.next().await, Some(Ok(_))));
+        assert!(executions.next().await.is_none());
+    }
+}
@@ -0,0 +1,67 @@
+use super::codec::{self, Frame};
+use crate::types::{CTraderClient, CTraderClientBuilder, Environment};
+use futures_util::StreamExt;
+use std::future::Future;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{WebSocketStream, accept_async, tungstenite::Message};
+
+/// The server side of a connection to the mock server
+pub(crate) type ServerStream = WebSocketStream<TcpStream>;
+
+/// Start a websocket server standing in for the CTrader OpenAPI, accepting a single client.
+/// Returns the URL to connect to and the task running `session` on the accepted connection.
+/// * session - Plays the server side of the connection
+pub(crate) async fn mock_server<F, Fut, T>(
+    session: F,
+) -> anyhow::Result<(String, JoinHandle<anyhow::Result<T>>)>
+where
+    F: FnOnce(ServerStream) -> Fut + Send + 'static,
+    Fut: Future<Output = anyhow::Result<T>> + Send,
+    T: Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("ws://{}", listener.local_addr()?);
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await?;
+        session(accept_async(stream).await?).await
+    });
+
+    Ok((url, server_handle))
+}
+
+/// Read the next request sent by the client, `None` once it sent a close frame.
+/// Fails when the connection ends without a close frame.
+pub(crate) async fn next_request(ws_stream: &mut ServerStream) -> anyhow::Result<Option<Frame>> {
+    while let Some(msg) = ws_stream.next().await {
+        match msg? {
+            Message::Binary(data) => return Ok(Some(codec::decode(&data)?)),
+            Message::Close(_) => return Ok(None),
+            _ => {}
+        }
+    }
+
+    Err(anyhow::anyhow!("the client did not send a close frame"))
+}
+
+/// Configure a client with test credentials for the mock server at `url`.
+/// Heartbeats are rare so they don't get in the way of the requests a test expects, and the
+/// read idle timeout outlasts them.
+pub(crate) fn builder(url: String) -> CTraderClientBuilder {
+    CTraderClient::builder()
+        .environment(Environment::Custom { url })
+        .client_id("id")
+        .client_secret("secret")
+        .access_token("access")
+        .refresh_token("refresh")
+        .redirect_url("http://localhost")
+        .heartbeat_interval(Duration::from_secs(60))
+        .read_idle_timeout(Duration::from_secs(120))
+}
+
+/// Connect a client with test credentials to the mock server at `url`.
+pub(crate) async fn connect(url: String) -> anyhow::Result<CTraderClient> {
+    Ok(builder(url).connect().await?)
+}
@@ -1,111 +1,446 @@
+mod account;
 mod auth;
+mod builder;
+pub(crate) mod codec;
 mod connector;
+mod discovery;
 mod endpoint;
+mod events;
 mod middleware;
+#[cfg(test)]
+mod mock;
+mod order;
+mod payload;
+mod random;
 mod receiver;
+mod redirect;
+mod refresh;
+mod secret;
 mod sender;
+mod session;
+mod shutdown;
+mod store;
 
 pub mod traits;
 
+use crate::client::connector::{Connector, WsConnector};
 use crate::client::{receiver::on_message, sender::send_heartbeat};
+use crate::error::{CTraderError, CTraderResult, ConnectorError};
 use crate::openapi::{
-    ProtoMessage, ProtoOaAccountAuthReq, ProtoOaAccountLogoutReq, ProtoOaApplicationAuthReq,
+    ProtoErrorRes, ProtoHeartbeatEvent, ProtoOaAccountAuthRes, ProtoOaAccountLogoutRes,
+    ProtoOaApplicationAuthRes, ProtoOaAssetClassListRes, ProtoOaAssetListRes,
+    ProtoOaDealOffsetListRes, ProtoOaErrorRes, ProtoOaExecutionEvent,
+    ProtoOaGetAccountListByAccessTokenRes, ProtoOaGetCtidProfileByTokenRes,
+    ProtoOaGetPositionUnrealizedPnLRes, ProtoOaGetTickDataRes, ProtoOaGetTrendbarsRes,
+    ProtoOaOrderDetailsRes, ProtoOaOrderErrorEvent, ProtoOaOrderListByPositionIdRes,
+    ProtoOaReconcileRes, ProtoOaRefreshTokenRes, ProtoOaSubscribeSpotsRes,
+    ProtoOaSymbolCategoryListRes, ProtoOaSymbolsListRes, ProtoOaTraderRes,
+    ProtoOaUnsubscribeSpotsRes, ProtoPayloadType,
+};
+use crate::openapi::{
+    ProtoOaAccountAuthReq, ProtoOaAccountLogoutReq, ProtoOaApplicationAuthReq,
     ProtoOaAssetClassListReq, ProtoOaAssetListReq, ProtoOaCancelOrderReq, ProtoOaClosePositionReq,
     ProtoOaDealOffsetListReq, ProtoOaGetAccountListByAccessTokenReq,
-    ProtoOaGetPositionUnrealizedPnLReq, ProtoOaGetTickDataReq, ProtoOaGetTrendbarsReq,
-    ProtoOaNewOrderReq, ProtoOaOrderDetailsReq, ProtoOaOrderListByPositionIdReq, ProtoOaQuoteType,
-    ProtoOaReconcileReq, ProtoOaRefreshTokenReq, ProtoOaSubscribeSpotsReq,
+    ProtoOaGetCtidProfileByTokenReq, ProtoOaGetPositionUnrealizedPnLReq, ProtoOaGetTickDataReq,
+    ProtoOaGetTrendbarsReq, ProtoOaOrderDetailsReq, ProtoOaOrderListByPositionIdReq,
+    ProtoOaQuoteType, ProtoOaReconcileReq, ProtoOaRefreshTokenReq, ProtoOaSubscribeSpotsReq,
     ProtoOaSymbolCategoryListReq, ProtoOaSymbolsListReq, ProtoOaTraderReq,
     ProtoOaUnsubscribeSpotsReq,
 };
+use crate::openapi::{
+    ProtoOaAmendPositionSltpReq, ProtoOaCashFlowHistoryListReq, ProtoOaCashFlowHistoryListRes,
+    ProtoOaDealListByPositionIdReq, ProtoOaDealListByPositionIdRes, ProtoOaDealListReq,
+    ProtoOaDealListRes, ProtoOaExpectedMarginReq, ProtoOaExpectedMarginRes,
+    ProtoOaGetDynamicLeverageByIdReq, ProtoOaGetDynamicLeverageByIdRes, ProtoOaMarginCall,
+    ProtoOaMarginCallListReq, ProtoOaMarginCallListRes, ProtoOaMarginCallUpdateReq,
+    ProtoOaMarginCallUpdateRes, ProtoOaOrderListReq, ProtoOaOrderListRes, ProtoOaSymbolByIdReq,
+    ProtoOaSymbolByIdRes, ProtoOaSymbolsForConversionReq, ProtoOaSymbolsForConversionRes,
+    ProtoOaVersionReq, ProtoOaVersionRes,
+};
 use crate::openapi::{ProtoOaOrderType, ProtoOaTradeSide};
-use endpoint::Endpoints;
-use prost::Message;
+use crate::openapi::{
+    ProtoOaSubscribeDepthQuotesReq, ProtoOaSubscribeDepthQuotesRes,
+    ProtoOaSubscribeLiveTrendbarReq, ProtoOaSubscribeLiveTrendbarRes, ProtoOaTrendbarPeriod,
+    ProtoOaUnsubscribeDepthQuotesReq, ProtoOaUnsubscribeDepthQuotesRes,
+    ProtoOaUnsubscribeLiveTrendbarReq, ProtoOaUnsubscribeLiveTrendbarRes,
+};
+use serde::{Serialize, de::DeserializeOwned};
 
-use crate::types::{Auth, CTraderClient};
+use crate::client::traits::Payload;
+use crate::types::{
+    AmendOrder, Auth, CTraderClient, CTraderClientBuilder, ClientGuard, Event, OrderRequest,
+    Session,
+};
 
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{SinkExt, StreamExt, stream::SplitStream};
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
-use std::{collections::HashMap, sync::Arc};
-use tokio::sync::Mutex;
-use tokio::task::JoinHandle;
-use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+};
+use tokio::sync::{Mutex, Notify, broadcast, oneshot, watch};
+use tokio::time::Instant;
+
+type WsRead = SplitStream<connector::WsStream>;
+
+/// Number of events buffered for each subscriber before the slowest one starts lagging
+const DEFAULT_EVENT_BUFFER: usize = 1024;
+
+/// Idle time after which a heartbeat is sent, well below the 30s the server tolerates
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Time without any frame from the server after which the connection is given up, the server
+/// sends a heartbeat at least every 10s
+const DEFAULT_READ_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Shortest read idle timeout accepted, anything below would drop healthy quiet connections
+const MIN_READ_IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time to wait for a response when no other timeout has been configured
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time to wait for a single connection attempt when no other timeout has been configured
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[allow(dead_code)]
 impl CTraderClient {
-    /// Create a new CTrader OpenAPi client instance
-    /// * is_demo - Specify whether to use Demo/Live host
-    /// * app_client_id - CTrader Application Client ID
-    /// * app_client_secret - CTrader Application Secret
-    /// * app_redirect_url - Ctrader Application Redirect URL
-    /// * refresh_token - CTrader Refresh Token
-    pub async fn new(
-        is_demo: bool,
-        app_client_id: String,
-        app_access_token: String,
-        app_client_secret: String,
-        app_redirect_url: String,
-        refresh_token: String,
-    ) -> Result<(Self, JoinHandle<()>, JoinHandle<()>), anyhow::Error> {
-        let host = if is_demo {
-            Endpoints::DEMO_HOST_WS_URI
-        } else {
-            Endpoints::LIVE_HOST_URI
+    /// Connect to the CTrader OpenAPI and spawn the reader, heartbeat and token refresh tasks
+    /// * builder - The settings of the client
+    /// * auth - The application and account credentials
+    pub(crate) async fn connect(builder: CTraderClientBuilder, auth: Auth) -> CTraderResult<Self> {
+        let transport = builder.transport;
+        let environment = builder.environment;
+        let url = environment.url(transport);
+
+        let shutdown = Arc::new(watch::channel(false).0);
+        let connector = WsConnector::new(
+            url,
+            builder.reconnect_policy,
+            builder.connect_timeout,
+            shutdown.subscribe(),
+        );
+        let web_socket_stream = connector.connect(Session::default()).await?;
+
+        let (ws_write, ws_read) = web_socket_stream.split();
+
+        let outgoing = Arc::new(Mutex::new(ws_write));
+
+        let (events, _) = broadcast::channel(builder.event_buffer);
+
+        let last_sent = Arc::new(Mutex::new(Instant::now()));
+        let heartbeat_due = Arc::new(Notify::new());
+
+        let client = Self {
+            auth: Arc::new(Mutex::new(auth)),
+            ws_write: outgoing.clone(),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            next_msg_id: Arc::new(AtomicU64::new(1)),
+            events: events.clone(),
+            last_sent: last_sent.clone(),
+            session: Arc::new(Mutex::new(Session::default())),
+            request_timeout: builder.request_timeout,
+            transport,
+            environment,
+            token_store: builder.token_store,
+            tasks: Arc::new(std::sync::Mutex::new(Vec::new())),
+            shutdown,
+            guard: None,
+        };
+
+        // The tasks get the client before the guard is added, so only the handles returned to
+        // the user keep it running
+        let background = client.clone();
+        let client = Self {
+            guard: Some(Arc::new(ClientGuard {
+                shutdown: client.shutdown.clone(),
+                tasks: client.tasks.clone(),
+            })),
+            ..client
         };
 
-        let url = format!("{}:{}", host, Endpoints::PROTOBUF_PORT);
+        let token_refresh_handle =
+            tokio::spawn(background.clone().run_token_refresh(client.subscribe()));
+        let message_handle = tokio::spawn(background.run_reader(
+            connector,
+            ws_read,
+            heartbeat_due.clone(),
+            builder.read_idle_timeout,
+        ));
+        let heartbeat_handle = tokio::spawn(send_heartbeat(
+            outgoing,
+            last_sent,
+            heartbeat_due,
+            events,
+            transport,
+            builder.heartbeat_interval,
+            client.shutdown.subscribe(),
+        ));
+
+        client
+            .tasks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .extend([message_handle, heartbeat_handle, token_refresh_handle]);
+
+        Ok(client)
+    }
+
+    /// Wait until the background tasks of the client have stopped
+    pub async fn join(&self) {
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap_or_else(|e| e.into_inner()));
+
+        for task in tasks {
+            if let Err(e) = task.await
+                && e.is_panic()
+            {
+                tracing::error!("CTrader client task panicked: {}", e);
+            }
+        }
+    }
+
+    /// Read messages until the connection drops or stays silent for `idle_timeout`, then
+    /// reconnect and restore the session.
+    /// Stops when the connector gives up or the client shuts down.
+    async fn run_reader(
+        self,
+        connector: WsConnector,
+        ws_read: WsRead,
+        heartbeat_due: Arc<Notify>,
+        idle_timeout: Duration,
+    ) {
+        let mut ws_read = ws_read;
+
+        loop {
+            on_message(
+                Arc::new(Mutex::new(ws_read)),
+                self.pending.clone(),
+                self.events.clone(),
+                self.session.clone(),
+                heartbeat_due.clone(),
+                idle_timeout,
+            )
+            .await;
+
+            let _ = self.events.send(Event::Disconnected);
+
+            if *self.shutdown.borrow() {
+                return;
+            }
+
+            let session = self.session.lock().await.clone();
+
+            let web_socket_stream = match connector.reconnect(session).await {
+                Ok(web_socket_stream) => web_socket_stream,
+                Err(ConnectorError::Stopped { .. }) => return,
+                Err(e) => {
+                    tracing::error!("Unable to reconnect to CTrader OpenAPI: {}", e);
+                    let _ = self.events.send(Event::ConnectionUnhealthy {
+                        reason: e.to_string(),
+                    });
+                    return;
+                }
+            };
+
+            let (ws_write, read) = web_socket_stream.split();
+            *self.ws_write.lock().await = ws_write;
+            ws_read = read;
+
+            // Responses are read by `on_message`, so restore the session alongside it
+            let client = self.clone();
+            let restore_handle = tokio::spawn(async move {
+                match client.restore_session().await {
+                    Ok(()) => {
+                        let _ = client.events.send(Event::Reconnected);
+                    }
+                    Err(e) => {
+                        tracing::error!("Unable to restore the CTrader session: {}", e);
+                        let _ = client.events.send(Event::ConnectionUnhealthy {
+                            reason: format!("unable to restore the session: {}", e),
+                        });
+                    }
+                }
+            });
+
+            // Track it so `join` and `shutdown` wait for it, forgetting earlier finished ones
+            let mut tasks = self.tasks.lock().unwrap_or_else(|e| e.into_inner());
+            tasks.retain(|task| !task.is_finished());
+            tasks.push(restore_handle);
+        }
+    }
+
+    /// Re-run application auth, then re-authorize every account and re-subscribe every
+    /// stream that was active before the connection dropped.
+    /// A failing account or subscription does not stop the others from being restored, the
+    /// subscriptions of an account that could not be authorized are skipped. Every failure is
+    /// logged and returned together in `CTraderError::SessionRestore`.
+    async fn restore_session(&self) -> CTraderResult<()> {
+        let session = self.session.lock().await.clone();
 
         tracing::info!(
-            "Connecting Async Client to CTrader Endpoint {} OpenAPI",
-            url
+            "Restoring CTrader session with {} accounts",
+            session.accounts.len()
         );
 
-        // Try connection multiple attempts
-        let web_socket_stream = loop {
-            let web_socket_stream = if let Ok((ws_stream, _)) = connect_async(url.as_str()).await {
-                Some(ws_stream)
-            } else {
-                tracing::warn!("Error connecting to {} retrying in 5s ", url);
-                None
-            };
+        // Nothing else can be restored without the application
+        self.send_application_auth_request().await?;
+
+        let mut errors = Vec::new();
+        let mut keep = |result: CTraderResult<()>| match result {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("Unable to restore part of the CTrader session: {}", e);
+                errors.push(e);
+                false
+            }
+        };
 
-            if web_socket_stream.is_some() {
-                tracing::info!("Successfully Connected Async Client to CTrader OpenAPI");
-                break web_socket_stream.unwrap();
+        let mut authorized = BTreeSet::new();
+        for account_id in &session.accounts {
+            let result = self.send_set_account_request(*account_id).await;
+            if keep(result.map(|_| ())) {
+                authorized.insert(*account_id);
+            }
+        }
+
+        // Group spot subscriptions so each account needs one request per timestamp setting
+        let mut spots: BTreeMap<(i64, bool), Vec<i64>> = BTreeMap::new();
+        for ((account_id, symbol_id), timestamp) in &session.spots {
+            spots
+                .entry((*account_id, *timestamp))
+                .or_default()
+                .push(*symbol_id);
+        }
+        for ((account_id, timestamp), symbol_ids) in spots {
+            if authorized.contains(&account_id) {
+                let result = self
+                    .send_subscribe_spots_request(account_id, symbol_ids, timestamp)
+                    .await;
+                keep(result.map(|_| ()));
+            }
+        }
+
+        // Live trendbars require the spot subscription restored above
+        for (account_id, symbol_id, period) in &session.trendbars {
+            if authorized.contains(account_id) {
+                let result = match ProtoOaTrendbarPeriod::try_from(*period) {
+                    Ok(period) => self
+                        .send_subscribe_live_trendbar_request(*account_id, *symbol_id, period)
+                        .await
+                        .map(|_| ()),
+                    Err(e) => Err(CTraderError::Other(e.to_string())),
+                };
+                keep(result);
+            }
+        }
+
+        let mut depth: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        for (account_id, symbol_id) in &session.depth {
+            depth.entry(*account_id).or_default().push(*symbol_id);
+        }
+        for (account_id, symbol_ids) in depth {
+            if authorized.contains(&account_id) {
+                let result = self
+                    .send_subscribe_depth_quotes_request(account_id, symbol_ids)
+                    .await;
+                keep(result.map(|_| ()));
             }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(CTraderError::SessionRestore { errors })
+        }
+    }
 
-            tokio::time::sleep(Duration::from_secs(5)).await;
+    /// Send heartbeat to CTrader API to ensure the connection is a live
+    pub async fn send_heartbeat(&self) -> CTraderResult<()> {
+        let heartbeat = ProtoHeartbeatEvent {
+            payload_type: Some(ProtoPayloadType::HeartbeatEvent as i32),
         };
+        let frame = self.transport.encode(&heartbeat, None)?;
 
-        let auth = Auth::new(
-            app_client_id,
-            app_access_token,
-            app_client_secret,
-            app_redirect_url,
-            refresh_token,
-        );
+        self.ws_write.lock().await.send(frame).await?;
+        *self.last_sent.lock().await = Instant::now();
 
-        let (ws_write, ws_read) = web_socket_stream.split();
-        let incoming = Arc::new(Mutex::new(ws_read));
+        Ok(())
+    }
 
-        let outgoing = Arc::new(Mutex::new(ws_write));
+    /// Subscribe to the events pushed by the CTrader OpenAPI
+    pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.events.subscribe()
+    }
 
-        let message_handle = tokio::spawn(on_message(incoming));
-        let heartbeat_handle = tokio::spawn(send_heartbeat(outgoing.clone()));
+    /// Return a copy of the client that waits `timeout` for each response
+    pub fn with_request_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            request_timeout: timeout,
+            ..self.clone()
+        }
+    }
 
-        Ok((
-            Self {
-                auth,
-                ws_write: outgoing.clone(),
-            },
-            message_handle,
-            heartbeat_handle,
-        ))
+    /// Generate the next `client_msg_id` used to correlate a request with its response
+    fn next_client_msg_id(&self) -> String {
+        self.next_msg_id.fetch_add(1, Ordering::Relaxed).to_string()
     }
 
-    /// Send heartbeat to CTrader API to ensure the connection is a live
-    async fn send_heartbeat(&self) {}
+    /// Send a request using the configured transport and wait for the matching response
+    async fn request<Req: Payload + Serialize, Res: Payload + DeserializeOwned>(
+        &self,
+        message: &Req,
+    ) -> CTraderResult<Res> {
+        let client_msg_id = self.next_client_msg_id();
+        let (tx, rx) = oneshot::channel();
+
+        self.pending.lock().await.insert(client_msg_id.clone(), tx);
+
+        let frame = match self.transport.encode(message, Some(client_msg_id.clone())) {
+            Ok(frame) => frame,
+            Err(err) => {
+                self.pending.lock().await.remove(&client_msg_id);
+                return Err(err);
+            }
+        };
+
+        if let Err(err) = self.ws_write.lock().await.send(frame).await {
+            self.pending.lock().await.remove(&client_msg_id);
+            return Err(err.into());
+        }
+        *self.last_sent.lock().await = Instant::now();
+
+        let frame = match tokio::time::timeout(self.request_timeout, rx).await {
+            Ok(Ok(frame)) => frame,
+            Ok(Err(_)) => return Err(CTraderError::Disconnected(client_msg_id)),
+            Err(_) => {
+                self.pending.lock().await.remove(&client_msg_id);
+                return Err(CTraderError::TimeoutError {
+                    task: format!(
+                        "request {} (payload type {})",
+                        client_msg_id,
+                        Req::PAYLOAD_TYPE
+                    ),
+                    duration: self.request_timeout,
+                });
+            }
+        };
+
+        match frame.payload_type {
+            ProtoErrorRes::PAYLOAD_TYPE => Err(frame.payload::<ProtoErrorRes>()?.into()),
+            ProtoOaErrorRes::PAYLOAD_TYPE => Err(frame.payload::<ProtoOaErrorRes>()?.into()),
+            ProtoOaOrderErrorEvent::PAYLOAD_TYPE => {
+                Err(frame.payload::<ProtoOaOrderErrorEvent>()?.into())
+            }
+            payload_type if payload_type == Res::PAYLOAD_TYPE => frame.payload(),
+            payload_type => Err(CTraderError::Other(format!(
+                "expected payload type {} in response to request {}, got {}",
+                Res::PAYLOAD_TYPE,
+                client_msg_id,
+                payload_type
+            ))),
+        }
+    }
 
     /// Send a new LIMIT order request
     pub async fn send_new_limit_order(
@@ -115,19 +450,10 @@ impl CTraderClient {
         trade_side: ProtoOaTradeSide,
         volume: i64,
         price: f64,
-    ) -> Result<(), anyhow::Error> {
-        let order_type = ProtoOaOrderType::Limit;
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let order = OrderRequest::limit(symbol_id, trade_side, volume, price);
 
-        self.send_new_order_request(
-            account_id,
-            symbol_id,
-            order_type,
-            trade_side,
-            volume,
-            Some(price),
-        )
-        .await?;
-        Ok(())
+        self.send_new_order(account_id, &order).await
     }
 
     /// Send a new MARKET order request
@@ -137,12 +463,10 @@ impl CTraderClient {
         symbol_id: i64,
         trade_side: ProtoOaTradeSide,
         volume: i64,
-    ) -> Result<(), anyhow::Error> {
-        let order_type = ProtoOaOrderType::Market;
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let order = OrderRequest::market(symbol_id, trade_side, volume);
 
-        self.send_new_order_request(account_id, symbol_id, order_type, trade_side, volume, None)
-            .await?;
-        Ok(())
+        self.send_new_order(account_id, &order).await
     }
 
     /// Send a new STOP order request
@@ -153,376 +477,329 @@ impl CTraderClient {
         trade_side: ProtoOaTradeSide,
         volume: i64,
         price: f64,
-    ) -> Result<(), anyhow::Error> {
-        let order_type = ProtoOaOrderType::Stop;
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let order = OrderRequest::stop(symbol_id, trade_side, volume, price);
 
-        self.send_new_order_request(
-            account_id,
-            symbol_id,
-            order_type,
-            trade_side,
-            volume,
-            Some(price),
-        )
-        .await?;
-        Ok(())
+        self.send_new_order(account_id, &order).await
     }
 
-    pub async fn send_refresh_token_request(&self) -> Result<(), anyhow::Error> {
+    pub async fn send_refresh_token_request(&self) -> CTraderResult<ProtoOaRefreshTokenRes> {
         tracing::info!("Refreshing Application Token");
 
         let req = ProtoOaRefreshTokenReq {
-            refresh_token: self.auth.ctrader_refresh_token.to_string(),
-            payload_type: Some(2173),
+            refresh_token: self
+                .auth
+                .lock()
+                .await
+                .ctrader_refresh_token
+                .expose()
+                .to_string(),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2173; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
     /// Authenticate the client to the CTrader APi
-    pub async fn send_application_auth_request(&mut self) -> Result<(), anyhow::Error> {
+    pub async fn send_application_auth_request(&self) -> CTraderResult<ProtoOaApplicationAuthRes> {
         tracing::info!("Authenticating Async Client to CTrader OpenAPI");
 
+        let auth = self.auth.lock().await.clone();
+
         let req = ProtoOaApplicationAuthReq {
-            client_id: self.auth.ctrader_client_id.to_string(),
-            client_secret: self.auth.ctrader_client_secret.to_string(),
-            payload_type: Some(2100),
+            client_id: auth.ctrader_client_id,
+            client_secret: auth.ctrader_client_secret.expose().to_string(),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2100; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_set_account_request(&mut self, account_id: i64) -> Result<(), anyhow::Error> {
+    pub async fn send_set_account_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaAccountAuthRes> {
         tracing::info!("Setting Active account to {}", account_id);
 
         let req = ProtoOaAccountAuthReq {
             ctid_trader_account_id: account_id,
-            access_token: self.auth.ctrader_access_token.to_string(),
-            payload_type: Some(2102),
+            access_token: self
+                .auth
+                .lock()
+                .await
+                .ctrader_access_token
+                .expose()
+                .to_string(),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2102; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+        let res = self.request(&req).await?;
+        self.session.lock().await.accounts.insert(account_id);
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        Ok(res)
     }
 
-    pub async fn send_get_account_list_by_access_token_request(&self) -> Result<(), anyhow::Error> {
+    pub async fn send_get_account_list_by_access_token_request(
+        &self,
+    ) -> CTraderResult<ProtoOaGetAccountListByAccessTokenRes> {
         let req = ProtoOaGetAccountListByAccessTokenReq {
-            access_token: self.auth.ctrader_access_token.to_string(),
-            payload_type: Some(2149),
+            access_token: self
+                .auth
+                .lock()
+                .await
+                .ctrader_access_token
+                .expose()
+                .to_string(),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2149; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        self.request(&req).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    pub async fn send_get_ctid_profile_by_token_request(
+        &self,
+    ) -> CTraderResult<ProtoOaGetCtidProfileByTokenRes> {
+        let req = ProtoOaGetCtidProfileByTokenReq {
+            access_token: self
+                .auth
+                .lock()
+                .await
+                .ctrader_access_token
+                .expose()
+                .to_string(),
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_account_logout_request(&self, account_id: i64) -> Result<(), anyhow::Error> {
+    pub async fn send_account_logout_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaAccountLogoutRes> {
         let req = ProtoOaAccountLogoutReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2162),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2162; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+        let res = self.request(&req).await?;
+        self.session.lock().await.forget_account(account_id);
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        Ok(res)
     }
 
-    pub async fn send_asset_list_request(&self, account_id: i64) -> Result<(), anyhow::Error> {
+    pub async fn send_asset_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaAssetListRes> {
         let req = ProtoOaAssetListReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2112),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2112; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
     pub async fn send_asset_class_list_request(
         &self,
         account_id: i64,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaAssetClassListRes> {
         let req = ProtoOaAssetClassListReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2153),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2153; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
     pub async fn send_symbol_category_list_request(
         &self,
         account_id: i64,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaSymbolCategoryListRes> {
         let req = ProtoOaSymbolCategoryListReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2160),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2160; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
     pub async fn send_symbols_list_request(
         &self,
         account_id: i64,
         include_archived_symbols: bool,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaSymbolsListRes> {
         let req = ProtoOaSymbolsListReq {
             ctid_trader_account_id: account_id,
             include_archived_symbols: Some(include_archived_symbols),
-            payload_type: Some(2114),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2114; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_trader_request(&self, account_id: i64) -> Result<(), anyhow::Error> {
+    pub async fn send_trader_request(&self, account_id: i64) -> CTraderResult<ProtoOaTraderRes> {
         let req = ProtoOaTraderReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2121),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2121; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
     pub async fn send_unsubscribe_spots_request(
         &self,
         account_id: i64,
         symbol_id: Vec<i64>,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaUnsubscribeSpotsRes> {
         let req = ProtoOaUnsubscribeSpotsReq {
             ctid_trader_account_id: account_id,
-            symbol_id: symbol_id,
-            payload_type: Some(2129),
+            symbol_id: symbol_id.clone(),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2129; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        let res = self.request(&req).await?;
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+        // Live trendbars need the spot subscription, they cannot be restored without it
+        let mut session = self.session.lock().await;
+        for symbol_id in symbol_id {
+            session.forget_spots(account_id, symbol_id);
+        }
 
-        Ok(())
+        Ok(res)
     }
 
     pub async fn send_subscribe_spots_request(
         &self,
         account_id: i64,
         symbol_id: Vec<i64>,
-        time_in_seconds: usize,
         subscribe_to_spot_timestamp: bool,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaSubscribeSpotsRes> {
         let req = ProtoOaSubscribeSpotsReq {
             ctid_trader_account_id: account_id,
-            symbol_id: symbol_id,
+            symbol_id: symbol_id.clone(),
             subscribe_to_spot_timestamp: Some(subscribe_to_spot_timestamp),
-            payload_type: Some(2127),
+            ..Default::default()
+        };
+
+        let res = self.request(&req).await?;
+
+        let mut session = self.session.lock().await;
+        for symbol_id in symbol_id {
+            session
+                .spots
+                .insert((account_id, symbol_id), subscribe_to_spot_timestamp);
+        }
+
+        Ok(res)
+    }
+
+    pub async fn send_subscribe_live_trendbar_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaSubscribeLiveTrendbarRes> {
+        let req = ProtoOaSubscribeLiveTrendbarReq {
+            ctid_trader_account_id: account_id,
+            symbol_id,
+            period: period as i32,
+            ..Default::default()
+        };
+
+        let res = self.request(&req).await?;
+        self.session
+            .lock()
+            .await
+            .trendbars
+            .insert((account_id, symbol_id, period as i32));
+
+        Ok(res)
+    }
+
+    pub async fn send_unsubscribe_live_trendbar_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaUnsubscribeLiveTrendbarRes> {
+        let req = ProtoOaUnsubscribeLiveTrendbarReq {
+            ctid_trader_account_id: account_id,
+            symbol_id,
+            period: period as i32,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        let res = self.request(&req).await?;
+        self.session
+            .lock()
+            .await
+            .trendbars
+            .remove(&(account_id, symbol_id, period as i32));
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2127; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+        Ok(res)
+    }
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+    pub async fn send_subscribe_depth_quotes_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSubscribeDepthQuotesRes> {
+        let req = ProtoOaSubscribeDepthQuotesReq {
+            ctid_trader_account_id: account_id,
+            symbol_id: symbol_id.clone(),
+            ..Default::default()
+        };
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+        let res = self.request(&req).await?;
 
-        Ok(())
+        let mut session = self.session.lock().await;
+        for symbol_id in symbol_id {
+            session.depth.insert((account_id, symbol_id));
+        }
+
+        Ok(res)
+    }
+
+    pub async fn send_unsubscribe_depth_quotes_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeDepthQuotesRes> {
+        let req = ProtoOaUnsubscribeDepthQuotesReq {
+            ctid_trader_account_id: account_id,
+            symbol_id: symbol_id.clone(),
+            ..Default::default()
+        };
+
+        let res = self.request(&req).await?;
+
+        let mut session = self.session.lock().await;
+        for symbol_id in symbol_id {
+            session.depth.remove(&(account_id, symbol_id));
+        }
+
+        Ok(res)
     }
 
     pub async fn send_get_tick_data_request(
         &self,
         account_id: i64,
-        days: i64,
         quote_type: ProtoOaQuoteType,
         symbol_id: i64,
         from_timestamp: Option<i64>,
         to_timestamp: Option<i64>,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaGetTickDataRes> {
         let req = ProtoOaGetTickDataReq {
             ctid_trader_account_id: account_id,
-            symbol_id: symbol_id,
-            payload_type: Some(2145),
+            symbol_id,
             r#type: quote_type as i32,
-            from_timestamp: from_timestamp,
-            to_timestamp: to_timestamp,
+            from_timestamp,
+            to_timestamp,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2145; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
     pub async fn send_get_trendbars_request(
@@ -533,35 +810,23 @@ impl CTraderClient {
         count: u32,
         from_timestamp: i64,
         to_timestamp: i64,
-    ) -> Result<(), anyhow::Error> {
+    ) -> CTraderResult<ProtoOaGetTrendbarsRes> {
         let req = ProtoOaGetTrendbarsReq {
             ctid_trader_account_id: account_id,
             from_timestamp: Some(from_timestamp),
             to_timestamp: Some(to_timestamp),
-            payload_type: Some(2137),
-            period: period,
+            period,
             count: Some(count),
-            symbol_id: symbol_id,
+            symbol_id,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
-
-        // Create message with payload type prefix
-        let msg_id: u16 = 2137; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
-
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
-
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
-
-        Ok(())
+        self.request(&req).await
     }
 
+    /// Send a new MARKET, LIMIT or STOP order carrying the limit or stop price depending on
+    /// `order_type`. MARKET_RANGE and STOP_LIMIT orders are rejected as they also need a
+    /// slippage, see `send_new_market_range_order` and `send_new_stop_limit_order`.
     pub async fn send_new_order_request(
         &self,
         account_id: i64,
@@ -570,248 +835,647 @@ impl CTraderClient {
         trade_side: ProtoOaTradeSide,
         volume: i64,
         price: Option<f64>,
-    ) -> Result<(), anyhow::Error> {
-        let mut req = ProtoOaNewOrderReq {
-            ctid_trader_account_id: account_id,
-            symbol_id: symbol_id,
-            order_type: order_type as i32,
-            trade_side: trade_side as i32,
-            volume: volume,
-            payload_type: Some(2106),
-            ..Default::default()
-        };
-
-        match order_type {
-            ProtoOaOrderType::Limit => {
-                req.limit_price = price;
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let order = OrderRequest::new(symbol_id, order_type, trade_side, volume);
+
+        let order = match (order_type, price) {
+            // Both need a slippage this method can't take
+            (ProtoOaOrderType::MarketRange, _) => {
+                return Err(CTraderError::InvalidOrder(
+                    "MARKET_RANGE orders need a slippage, use send_new_market_range_order".into(),
+                ));
             }
+            (ProtoOaOrderType::StopLimit, _) => {
+                return Err(CTraderError::InvalidOrder(
+                    "STOP_LIMIT orders need a slippage, use send_new_stop_limit_order".into(),
+                ));
+            }
+            (_, None) => order,
+            (ProtoOaOrderType::Limit, Some(price)) => order.limit_price(price),
+            (ProtoOaOrderType::Stop, Some(price)) => order.stop_price(price),
+            (order_type, Some(_)) => {
+                return Err(CTraderError::InvalidOrder(format!(
+                    "{} orders take no price",
+                    order_type.as_str_name()
+                )));
+            }
+        };
 
-            ProtoOaOrderType::Stop => req.stop_price = price,
-
-            ProtoOaOrderType::Market => {}
-
-            ProtoOaOrderType::StopLossTakeProfit => {}
+        self.send_new_order(account_id, &order).await
+    }
 
-            _ => {}
+    pub async fn send_reconcile_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaReconcileRes> {
+        let req = ProtoOaReconcileReq {
+            ctid_trader_account_id: account_id,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2137; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+    pub async fn send_close_position_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let req = ProtoOaClosePositionReq {
+            ctid_trader_account_id: account_id,
+            position_id,
+            volume,
+            ..Default::default()
+        };
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        self.request(&req).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    pub async fn send_cancel_order_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let req = ProtoOaCancelOrderReq {
+            ctid_trader_account_id: account_id,
+            order_id,
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_reconcile_request(&self, account_id: i64) -> Result<(), anyhow::Error> {
-        let req = ProtoOaReconcileReq {
+    pub async fn send_deal_offset_list_request(
+        &self,
+        account_id: i64,
+        deal_id: i64,
+    ) -> CTraderResult<ProtoOaDealOffsetListRes> {
+        let req = ProtoOaDealOffsetListReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2124),
+            deal_id,
             ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2124; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+    pub async fn send_get_position_unrealized_pnl_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaGetPositionUnrealizedPnLRes> {
+        let req = ProtoOaGetPositionUnrealizedPnLReq {
+            ctid_trader_account_id: account_id,
+            ..Default::default()
+        };
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        self.request(&req).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    pub async fn send_order_details_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaOrderDetailsRes> {
+        let req = ProtoOaOrderDetailsReq {
+            ctid_trader_account_id: account_id,
+            order_id,
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_close_position_request(
+    pub async fn send_order_list_by_position_id_request(
         &self,
         account_id: i64,
         position_id: i64,
-        volume: i64,
-    ) -> Result<(), anyhow::Error> {
-        let req = ProtoOaClosePositionReq {
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListByPositionIdRes> {
+        let req = ProtoOaOrderListByPositionIdReq {
             ctid_trader_account_id: account_id,
-            position_id: position_id,
-            volume: volume,
-            payload_type: Some(2111),
+            position_id,
+            from_timestamp: Some(from_timestamp),
+            to_timestamp: Some(to_timestamp),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2111; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+    /// Request the version of the proxy server
+    pub async fn send_version_request(&self) -> CTraderResult<ProtoOaVersionRes> {
+        self.request(&ProtoOaVersionReq::default()).await
+    }
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+    /// Amend a pending order, only the fields set on `amend` are changed
+    pub async fn send_amend_order_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+        amend: &AmendOrder,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.request(&amend.to_request(account_id, order_id)).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    /// Amend the stop loss and take profit of a position, `None` removes the level
+    pub async fn send_amend_position_sltp_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        stop_loss: Option<f64>,
+        take_profit: Option<f64>,
+        trailing_stop_loss: bool,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let req = ProtoOaAmendPositionSltpReq {
+            ctid_trader_account_id: account_id,
+            position_id,
+            stop_loss,
+            take_profit,
+            trailing_stop_loss: Some(trailing_stop_loss),
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_cancel_order_request(
+    pub async fn send_symbol_by_id_request(
         &self,
         account_id: i64,
-        order_id: i64,
-    ) -> Result<(), anyhow::Error> {
-        let req = ProtoOaCancelOrderReq {
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSymbolByIdRes> {
+        let req = ProtoOaSymbolByIdReq {
             ctid_trader_account_id: account_id,
-            order_id: order_id,
-            payload_type: Some(2108),
+            symbol_id,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2108; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+    /// Request the chain of symbols converting the first asset into the last one
+    pub async fn send_symbols_for_conversion_request(
+        &self,
+        account_id: i64,
+        first_asset_id: i64,
+        last_asset_id: i64,
+    ) -> CTraderResult<ProtoOaSymbolsForConversionRes> {
+        let req = ProtoOaSymbolsForConversionReq {
+            ctid_trader_account_id: account_id,
+            first_asset_id,
+            last_asset_id,
+            ..Default::default()
+        };
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        self.request(&req).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    pub async fn send_deal_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+        max_rows: Option<i32>,
+    ) -> CTraderResult<ProtoOaDealListRes> {
+        let req = ProtoOaDealListReq {
+            ctid_trader_account_id: account_id,
+            from_timestamp: Some(from_timestamp),
+            to_timestamp: Some(to_timestamp),
+            max_rows,
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_deal_offset_list_request(
+    pub async fn send_deal_list_by_position_id_request(
         &self,
         account_id: i64,
-        deal_id: i64,
-    ) -> Result<(), anyhow::Error> {
-        let req = ProtoOaDealOffsetListReq {
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaDealListByPositionIdRes> {
+        let req = ProtoOaDealListByPositionIdReq {
             ctid_trader_account_id: account_id,
-            deal_id: deal_id,
-            payload_type: Some(2185),
+            position_id,
+            from_timestamp: Some(from_timestamp),
+            to_timestamp: Some(to_timestamp),
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2185; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+    pub async fn send_order_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListRes> {
+        let req = ProtoOaOrderListReq {
+            ctid_trader_account_id: account_id,
+            from_timestamp: Some(from_timestamp),
+            to_timestamp: Some(to_timestamp),
+            ..Default::default()
+        };
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        self.request(&req).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    /// Request the margin each of the volumes would require on the symbol
+    pub async fn send_expected_margin_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        volume: Vec<i64>,
+    ) -> CTraderResult<ProtoOaExpectedMarginRes> {
+        let req = ProtoOaExpectedMarginReq {
+            ctid_trader_account_id: account_id,
+            symbol_id,
+            volume,
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_get_position_unrealized_pnl_equest(
+    pub async fn send_cash_flow_history_list_request(
         &self,
         account_id: i64,
-    ) -> Result<(), anyhow::Error> {
-        let req = ProtoOaGetPositionUnrealizedPnLReq {
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaCashFlowHistoryListRes> {
+        let req = ProtoOaCashFlowHistoryListReq {
             ctid_trader_account_id: account_id,
-            payload_type: Some(2137),
+            from_timestamp,
+            to_timestamp,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2137; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+    pub async fn send_margin_call_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaMarginCallListRes> {
+        let req = ProtoOaMarginCallListReq {
+            ctid_trader_account_id: account_id,
+            ..Default::default()
+        };
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+        self.request(&req).await
+    }
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+    pub async fn send_margin_call_update_request(
+        &self,
+        account_id: i64,
+        margin_call: ProtoOaMarginCall,
+    ) -> CTraderResult<ProtoOaMarginCallUpdateRes> {
+        let req = ProtoOaMarginCallUpdateReq {
+            ctid_trader_account_id: account_id,
+            margin_call,
+            ..Default::default()
+        };
 
-        Ok(())
+        self.request(&req).await
     }
 
-    pub async fn send_order_details_request(
+    pub async fn send_get_dynamic_leverage_by_id_request(
         &self,
         account_id: i64,
-        order_id: i64,
-    ) -> Result<(), anyhow::Error> {
-        let req = ProtoOaOrderDetailsReq {
+        leverage_id: i64,
+    ) -> CTraderResult<ProtoOaGetDynamicLeverageByIdRes> {
+        let req = ProtoOaGetDynamicLeverageByIdReq {
             ctid_trader_account_id: account_id,
-            order_id: order_id,
-            payload_type: Some(2181),
+            leverage_id,
+            ..Default::default()
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        self.request(&req).await
+    }
+}
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2181; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::codec::Frame;
+    use crate::openapi::*;
+    use crate::types::ReconnectPolicy;
+    use anyhow::Context;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    /// List the requests of the Open API with the method of the client sending each of them.
+    /// A method or request type that does not exist fails to compile.
+    macro_rules! request_methods {
+        ($($request:ident => $method:ident,)*) => {{
+            $(let _ = ($request::PAYLOAD_TYPE, CTraderClient::$method);)*
+            [$(stringify!($request),)*]
+        }};
+    }
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+    /// Every request of the Open API must be reachable through a method of the client
+    #[test]
+    fn test_every_request_has_a_method() {
+        let covered = request_methods! {
+            ProtoOaApplicationAuthReq => send_application_auth_request,
+            ProtoOaAccountAuthReq => send_set_account_request,
+            ProtoOaVersionReq => send_version_request,
+            ProtoOaNewOrderReq => send_new_order,
+            ProtoOaCancelOrderReq => send_cancel_order_request,
+            ProtoOaAmendOrderReq => send_amend_order_request,
+            ProtoOaAmendPositionSltpReq => send_amend_position_sltp_request,
+            ProtoOaClosePositionReq => send_close_position_request,
+            ProtoOaAssetListReq => send_asset_list_request,
+            ProtoOaSymbolsListReq => send_symbols_list_request,
+            ProtoOaSymbolByIdReq => send_symbol_by_id_request,
+            ProtoOaSymbolsForConversionReq => send_symbols_for_conversion_request,
+            ProtoOaAssetClassListReq => send_asset_class_list_request,
+            ProtoOaTraderReq => send_trader_request,
+            ProtoOaReconcileReq => send_reconcile_request,
+            ProtoOaDealListReq => send_deal_list_request,
+            ProtoOaOrderListReq => send_order_list_request,
+            ProtoOaExpectedMarginReq => send_expected_margin_request,
+            ProtoOaCashFlowHistoryListReq => send_cash_flow_history_list_request,
+            ProtoOaGetAccountListByAccessTokenReq => send_get_account_list_by_access_token_request,
+            ProtoOaRefreshTokenReq => send_refresh_token_request,
+            ProtoOaSubscribeSpotsReq => send_subscribe_spots_request,
+            ProtoOaUnsubscribeSpotsReq => send_unsubscribe_spots_request,
+            ProtoOaSubscribeLiveTrendbarReq => send_subscribe_live_trendbar_request,
+            ProtoOaUnsubscribeLiveTrendbarReq => send_unsubscribe_live_trendbar_request,
+            ProtoOaGetTrendbarsReq => send_get_trendbars_request,
+            ProtoOaGetTickDataReq => send_get_tick_data_request,
+            ProtoOaGetCtidProfileByTokenReq => send_get_ctid_profile_by_token_request,
+            ProtoOaSubscribeDepthQuotesReq => send_subscribe_depth_quotes_request,
+            ProtoOaUnsubscribeDepthQuotesReq => send_unsubscribe_depth_quotes_request,
+            ProtoOaSymbolCategoryListReq => send_symbol_category_list_request,
+            ProtoOaAccountLogoutReq => send_account_logout_request,
+            ProtoOaMarginCallListReq => send_margin_call_list_request,
+            ProtoOaMarginCallUpdateReq => send_margin_call_update_request,
+            ProtoOaGetDynamicLeverageByIdReq => send_get_dynamic_leverage_by_id_request,
+            ProtoOaDealListByPositionIdReq => send_deal_list_by_position_id_request,
+            ProtoOaOrderDetailsReq => send_order_details_request,
+            ProtoOaOrderListByPositionIdReq => send_order_list_by_position_id_request,
+            ProtoOaDealOffsetListReq => send_deal_offset_list_request,
+            ProtoOaGetPositionUnrealizedPnLReq => send_get_position_unrealized_pnl_request,
+        };
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+        let requests: Vec<&str> = ProtoOaPayloadType::MESSAGES
+            .iter()
+            .map(|(message, _)| *message)
+            .filter(|message| message.ends_with("Req"))
+            .collect();
+
+        assert!(!requests.is_empty());
+
+        for request in requests {
+            assert!(
+                covered.contains(&request),
+                "{} has no method on CTraderClient",
+                request
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_fails_transiently_when_the_connection_drops() -> anyhow::Result<()> {
+        // Read the request and drop the connection without answering it
+        let (url, _server_handle) = mock::mock_server(|mut ws_stream| async move {
+            mock::next_request(&mut ws_stream).await?;
+            drop(ws_stream);
+            anyhow::Ok(())
+        })
+        .await?;
+        let client = mock::connect(url).await?;
+
+        let err = tokio::time::timeout(Duration::from_secs(5), client.send_version_request())
+            .await?
+            .expect_err("the request was answered");
+
+        assert!(matches!(err, CTraderError::Disconnected(_)), "{:?}", err);
+        assert!(err.is_transient());
+        assert!(!err.is_retryable());
 
         Ok(())
     }
 
-    pub async fn send_order_list_by_position_id_request(
-        &self,
-        account_id: i64,
-        position_id: i64,
-        from_timestamp: i64,
-        to_timestamp: i64,
-    ) -> Result<(), anyhow::Error> {
-        let req = ProtoOaOrderListByPositionIdReq {
-            ctid_trader_account_id: account_id,
-            position_id: position_id,
-            payload_type: Some(2183),
-            from_timestamp: Some(from_timestamp),
-            to_timestamp: Some(to_timestamp),
+    #[tokio::test]
+    async fn test_legacy_order_rejects_slippage_orders() -> anyhow::Result<()> {
+        let (url, _server_handle) =
+            mock::mock_server(
+                |mut ws_stream| async move { mock::next_request(&mut ws_stream).await },
+            )
+            .await?;
+        let client = mock::connect(url).await?;
+
+        for order_type in [ProtoOaOrderType::MarketRange, ProtoOaOrderType::StopLimit] {
+            let result = client
+                .send_new_order_request(7, 41, order_type, ProtoOaTradeSide::Buy, 1000, Some(1.1))
+                .await;
+
+            assert!(matches!(result, Err(CTraderError::InvalidOrder(_))));
+        }
+
+        Ok(())
+    }
+
+    /// Answer the session requests sent when connecting and when restoring the session
+    async fn answer(ws_stream: &mut mock::ServerStream, frame: &Frame) -> anyhow::Result<()> {
+        let client_msg_id = frame.client_msg_id.clone();
+
+        let res = match frame.payload_type {
+            ProtoOaApplicationAuthReq::PAYLOAD_TYPE => {
+                codec::encode(&ProtoOaApplicationAuthRes::default(), client_msg_id)
+            }
+            ProtoOaAccountAuthReq::PAYLOAD_TYPE => {
+                let req: ProtoOaAccountAuthReq = frame.payload()?;
+                let res = ProtoOaAccountAuthRes {
+                    ctid_trader_account_id: req.ctid_trader_account_id,
+                    ..Default::default()
+                };
+                codec::encode(&res, client_msg_id)
+            }
+            ProtoOaSubscribeSpotsReq::PAYLOAD_TYPE => {
+                let req: ProtoOaSubscribeSpotsReq = frame.payload()?;
+                let res = ProtoOaSubscribeSpotsRes {
+                    ctid_trader_account_id: req.ctid_trader_account_id,
+                    ..Default::default()
+                };
+                codec::encode(&res, client_msg_id)
+            }
+            payload_type => anyhow::bail!("unexpected request {}", payload_type),
         };
 
-        // Serialize to protobuf bytes
-        let mut buf = Vec::new();
-        req.encode(&mut buf)?;
+        ws_stream.send(res).await?;
 
-        // Create message with payload type prefix
-        let msg_id: u16 = 2183; // Application Auth Request ID
-        let mut message = Vec::with_capacity(2 + buf.len());
-        message.extend_from_slice(&msg_id.to_le_bytes()); // Message ID
-        message.extend_from_slice(&buf); // Protobuf payload
+        Ok(())
+    }
 
-        // Send as binary message
-        let ws_msg = WsMessage::Binary(message.into());
+    #[tokio::test]
+    async fn test_session_is_restored_after_reconnect() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(async move {
+            // Serve the account auth and spot subscription, then close the connection
+            let (stream, _) = listener.accept().await?;
+            let mut ws_stream = accept_async(stream).await?;
+            for _ in 0..2 {
+                let frame = mock::next_request(&mut ws_stream)
+                    .await?
+                    .context("the client closed the first connection")?;
+                answer(&mut ws_stream, &frame).await?;
+            }
+            ws_stream.close(None).await?;
+
+            // Record the requests restoring the session on the new connection
+            let (stream, _) = listener.accept().await?;
+            let mut ws_stream = accept_async(stream).await?;
+            let mut restored = Vec::new();
+            for _ in 0..3 {
+                let frame = mock::next_request(&mut ws_stream)
+                    .await?
+                    .context("the client closed the second connection")?;
+                restored.push(frame.payload_type);
+                answer(&mut ws_stream, &frame).await?;
+            }
+
+            anyhow::Ok((restored, ws_stream))
+        });
+
+        let client = mock::builder(url)
+            .reconnect_policy(ReconnectPolicy {
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(10),
+                ..Default::default()
+            })
+            .connect()
+            .await?;
+        let mut events = client.subscribe();
+
+        client.send_set_account_request(7).await?;
+        client
+            .send_subscribe_spots_request(7, vec![41], false)
+            .await?;
 
-        let _ = &mut self.ws_write.lock().await.send(ws_msg).await?;
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                match events.recv().await? {
+                    Event::Reconnected => return anyhow::Ok(()),
+                    Event::ConnectionUnhealthy { reason } => anyhow::bail!(reason),
+                    _ => {}
+                }
+            }
+        })
+        .await??;
+
+        let (restored, _ws_stream) = server_handle.await??;
+
+        assert_eq!(
+            restored,
+            [
+                ProtoOaApplicationAuthReq::PAYLOAD_TYPE,
+                ProtoOaAccountAuthReq::PAYLOAD_TYPE,
+                ProtoOaSubscribeSpotsReq::PAYLOAD_TYPE,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_restore_continues_past_a_failed_account() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(async move {
+            // Drop the first connection once the client sent a request
+            let (stream, _) = listener.accept().await?;
+            let mut ws_stream = accept_async(stream).await?;
+            mock::next_request(&mut ws_stream).await?;
+            drop(ws_stream);
+
+            // Reject the auth of account 7 and record what the client restores
+            let (stream, _) = listener.accept().await?;
+            let mut ws_stream = accept_async(stream).await?;
+            let mut restored = Vec::new();
+            for _ in 0..4 {
+                let frame = mock::next_request(&mut ws_stream)
+                    .await?
+                    .context("the client closed the second connection")?;
+
+                if frame.payload_type == ProtoOaAccountAuthReq::PAYLOAD_TYPE {
+                    let req: ProtoOaAccountAuthReq = frame.payload()?;
+                    restored.push((frame.payload_type, req.ctid_trader_account_id));
+
+                    if req.ctid_trader_account_id == 7 {
+                        let res = ProtoOaErrorRes {
+                            error_code: "CH_ACCESS_TOKEN_INVALID".into(),
+                            ..Default::default()
+                        };
+                        ws_stream
+                            .send(codec::encode(&res, frame.client_msg_id))
+                            .await?;
+                        continue;
+                    }
+                } else if frame.payload_type == ProtoOaSubscribeSpotsReq::PAYLOAD_TYPE {
+                    let req: ProtoOaSubscribeSpotsReq = frame.payload()?;
+                    restored.push((frame.payload_type, req.ctid_trader_account_id));
+                } else {
+                    restored.push((frame.payload_type, 0));
+                }
+
+                answer(&mut ws_stream, &frame).await?;
+            }
+
+            anyhow::Ok((restored, ws_stream))
+        });
+
+        let client = mock::builder(url)
+            .reconnect_policy(ReconnectPolicy {
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(10),
+                ..Default::default()
+            })
+            .connect()
+            .await?;
+        let mut events = client.subscribe();
+
+        {
+            let mut session = client.session.lock().await;
+            session.accounts.extend([7, 8]);
+            session.spots.insert((7, 41), false);
+            session.spots.insert((8, 41), false);
+        }
+
+        let result = client.send_version_request().await;
+        assert!(matches!(result, Err(CTraderError::Disconnected(_))));
+
+        let reason = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                match events.recv().await? {
+                    Event::Reconnected => anyhow::bail!("the failed account was not reported"),
+                    Event::ConnectionUnhealthy { reason } => return anyhow::Ok(reason),
+                    _ => {}
+                }
+            }
+        })
+        .await??;
+
+        let (restored, _ws_stream) = server_handle.await??;
+
+        assert!(reason.contains("CH_ACCESS_TOKEN_INVALID"), "{}", reason);
+        assert_eq!(
+            restored,
+            [
+                (ProtoOaApplicationAuthReq::PAYLOAD_TYPE, 0),
+                (ProtoOaAccountAuthReq::PAYLOAD_TYPE, 7),
+                (ProtoOaAccountAuthReq::PAYLOAD_TYPE, 8),
+                (ProtoOaSubscribeSpotsReq::PAYLOAD_TYPE, 8),
+            ]
+        );
 
         Ok(())
     }
@@ -0,0 +1,751 @@
+use crate::error::{CTraderError, CTraderResult};
+use crate::openapi::{
+    ProtoOaAmendOrderReq, ProtoOaExecutionEvent, ProtoOaNewOrderReq, ProtoOaOrderTriggerMethod,
+    ProtoOaOrderType, ProtoOaTimeInForce, ProtoOaTradeSide,
+};
+use crate::types::{AccountSession, AmendOrder, CTraderClient, OrderRequest};
+
+/// Longest comment accepted by the server
+const MAX_COMMENT_LEN: usize = 512;
+
+/// Longest label accepted by the server
+const MAX_LABEL_LEN: usize = 100;
+
+/// Longest client order id accepted by the server
+const MAX_CLIENT_ORDER_ID_LEN: usize = 50;
+
+impl OrderRequest {
+    /// Start an order of any type, prices and slippage are set with the other methods
+    /// * symbol_id - The symbol to trade
+    /// * order_type - The type of the order
+    /// * trade_side - BUY or SELL
+    /// * volume - The volume in 0.01 of a unit
+    pub fn new(
+        symbol_id: i64,
+        order_type: ProtoOaOrderType,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+    ) -> Self {
+        Self {
+            symbol_id,
+            order_type,
+            trade_side,
+            volume,
+            limit_price: None,
+            stop_price: None,
+            time_in_force: None,
+            expiration_timestamp: None,
+            stop_loss: None,
+            take_profit: None,
+            comment: None,
+            base_slippage_price: None,
+            slippage_in_points: None,
+            label: None,
+            position_id: None,
+            client_order_id: None,
+            relative_stop_loss: None,
+            relative_take_profit: None,
+            guaranteed_stop_loss: None,
+            trailing_stop_loss: None,
+            stop_trigger_method: None,
+        }
+    }
+
+    /// Start a MARKET order, filled right away at the best available price
+    pub fn market(symbol_id: i64, trade_side: ProtoOaTradeSide, volume: i64) -> Self {
+        Self::new(symbol_id, ProtoOaOrderType::Market, trade_side, volume)
+    }
+
+    /// Start a LIMIT order, filled at `price` or better
+    pub fn limit(symbol_id: i64, trade_side: ProtoOaTradeSide, volume: i64, price: f64) -> Self {
+        Self::new(symbol_id, ProtoOaOrderType::Limit, trade_side, volume).limit_price(price)
+    }
+
+    /// Start a STOP order, turned into a market order once the price reaches `price`
+    pub fn stop(symbol_id: i64, trade_side: ProtoOaTradeSide, volume: i64, price: f64) -> Self {
+        Self::new(symbol_id, ProtoOaOrderType::Stop, trade_side, volume).stop_price(price)
+    }
+
+    /// Start a MARKET_RANGE order, a market order only filled within `slippage_in_points` of
+    /// `base_slippage_price`
+    pub fn market_range(
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        base_slippage_price: f64,
+        slippage_in_points: i32,
+    ) -> Self {
+        Self::new(symbol_id, ProtoOaOrderType::MarketRange, trade_side, volume)
+            .base_slippage_price(base_slippage_price)
+            .slippage_in_points(slippage_in_points)
+    }
+
+    /// Start a STOP_LIMIT order, triggered at `stop_price` and then only filled within
+    /// `slippage_in_points` of it
+    pub fn stop_limit(
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        stop_price: f64,
+        slippage_in_points: i32,
+    ) -> Self {
+        Self::new(symbol_id, ProtoOaOrderType::StopLimit, trade_side, volume)
+            .stop_price(stop_price)
+            .slippage_in_points(slippage_in_points)
+    }
+
+    /// Set the price a LIMIT order is filled at or better
+    pub fn limit_price(mut self, limit_price: f64) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+
+    /// Set the price triggering a STOP or STOP_LIMIT order
+    pub fn stop_price(mut self, stop_price: f64) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Set when the order expires, GOOD_TILL_CANCEL by default
+    pub fn time_in_force(mut self, time_in_force: ProtoOaTimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Expire the order at the given Unix time in ms, making it GOOD_TILL_DATE unless another
+    /// time in force was set, which validation then rejects
+    pub fn expiration_timestamp(mut self, expiration_timestamp: i64) -> Self {
+        self.time_in_force
+            .get_or_insert(ProtoOaTimeInForce::GoodTillDate);
+        self.expiration_timestamp = Some(expiration_timestamp);
+        self
+    }
+
+    /// Set an absolute stop loss price, pending orders only
+    pub fn stop_loss(mut self, stop_loss: f64) -> Self {
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    /// Set an absolute take profit price, pending orders only
+    pub fn take_profit(mut self, take_profit: f64) -> Self {
+        self.take_profit = Some(take_profit);
+        self
+    }
+
+    /// Attach a comment of up to 512 characters
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Set the price the slippage of a MARKET_RANGE order is measured from
+    pub fn base_slippage_price(mut self, base_slippage_price: f64) -> Self {
+        self.base_slippage_price = Some(base_slippage_price);
+        self
+    }
+
+    /// Set the slippage allowed for a MARKET_RANGE or STOP_LIMIT order
+    pub fn slippage_in_points(mut self, slippage_in_points: i32) -> Self {
+        self.slippage_in_points = Some(slippage_in_points);
+        self
+    }
+
+    /// Attach a label of up to 100 characters
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Apply the order to an existing position
+    pub fn position_id(mut self, position_id: i64) -> Self {
+        self.position_id = Some(position_id);
+        self
+    }
+
+    /// Set an identifier of up to 50 characters chosen by the client
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Set the stop loss distance from the entry price, in 1/100000 of a price unit
+    pub fn relative_stop_loss(mut self, relative_stop_loss: i64) -> Self {
+        self.relative_stop_loss = Some(relative_stop_loss);
+        self
+    }
+
+    /// Set the take profit distance from the entry price, in 1/100000 of a price unit
+    pub fn relative_take_profit(mut self, relative_take_profit: i64) -> Self {
+        self.relative_take_profit = Some(relative_take_profit);
+        self
+    }
+
+    /// Set whether the stop loss is guaranteed
+    pub fn guaranteed_stop_loss(mut self, guaranteed_stop_loss: bool) -> Self {
+        self.guaranteed_stop_loss = Some(guaranteed_stop_loss);
+        self
+    }
+
+    /// Set whether the stop loss trails the price
+    pub fn trailing_stop_loss(mut self, trailing_stop_loss: bool) -> Self {
+        self.trailing_stop_loss = Some(trailing_stop_loss);
+        self
+    }
+
+    /// Set which price triggers a STOP or STOP_LIMIT order
+    pub fn stop_trigger_method(mut self, stop_trigger_method: ProtoOaOrderTriggerMethod) -> Self {
+        self.stop_trigger_method = Some(stop_trigger_method);
+        self
+    }
+
+    /// Check the fields fit the order type and each other, before the server rejects them
+    pub fn validate(&self) -> CTraderResult<()> {
+        use ProtoOaOrderType::{Limit, MarketRange, Stop, StopLimit, StopLossTakeProfit};
+
+        if self.order_type == StopLossTakeProfit {
+            return Err(CTraderError::InvalidOrder(
+                "STOP_LOSS_TAKE_PROFIT orders are only created by the server".into(),
+            ));
+        }
+
+        if self.volume <= 0 {
+            return Err(CTraderError::InvalidOrder(format!(
+                "volume must be positive, got {}",
+                self.volume
+            )));
+        }
+
+        self.check_field("limit_price", self.limit_price.is_some(), &[Limit], true)?;
+        self.check_field(
+            "stop_price",
+            self.stop_price.is_some(),
+            &[Stop, StopLimit],
+            true,
+        )?;
+        self.check_field(
+            "base_slippage_price",
+            self.base_slippage_price.is_some(),
+            &[MarketRange],
+            true,
+        )?;
+        self.check_field(
+            "slippage_in_points",
+            self.slippage_in_points.is_some(),
+            &[MarketRange, StopLimit],
+            true,
+        )?;
+        self.check_field(
+            "stop_trigger_method",
+            self.stop_trigger_method.is_some(),
+            &[Stop, StopLimit],
+            false,
+        )?;
+
+        // Market orders only take the stop loss and take profit relative to their fill price
+        let pending = [Limit, Stop, StopLimit];
+        self.check_field("stop_loss", self.stop_loss.is_some(), &pending, false)?;
+        self.check_field("take_profit", self.take_profit.is_some(), &pending, false)?;
+
+        if self.stop_loss.is_some() && self.relative_stop_loss.is_some() {
+            return Err(CTraderError::InvalidOrder(
+                "stop_loss and relative_stop_loss are mutually exclusive".into(),
+            ));
+        }
+        if self.take_profit.is_some() && self.relative_take_profit.is_some() {
+            return Err(CTraderError::InvalidOrder(
+                "take_profit and relative_take_profit are mutually exclusive".into(),
+            ));
+        }
+
+        let has_stop_loss = self.stop_loss.is_some() || self.relative_stop_loss.is_some();
+        if self.trailing_stop_loss == Some(true) && !has_stop_loss {
+            return Err(CTraderError::InvalidOrder(
+                "a trailing stop loss requires a stop loss".into(),
+            ));
+        }
+        if self.guaranteed_stop_loss == Some(true) && !has_stop_loss {
+            return Err(CTraderError::InvalidOrder(
+                "a guaranteed stop loss requires a stop loss".into(),
+            ));
+        }
+
+        let good_till_date = self.time_in_force == Some(ProtoOaTimeInForce::GoodTillDate);
+        if good_till_date != self.expiration_timestamp.is_some() {
+            return Err(CTraderError::InvalidOrder(
+                "expiration_timestamp must be set exactly for GOOD_TILL_DATE orders".into(),
+            ));
+        }
+
+        let prices = [
+            ("limit_price", self.limit_price),
+            ("stop_price", self.stop_price),
+            ("stop_loss", self.stop_loss),
+            ("take_profit", self.take_profit),
+            ("base_slippage_price", self.base_slippage_price),
+        ];
+        for (field, price) in prices {
+            if let Some(price) = price
+                && !(price.is_finite() && price > 0.0)
+            {
+                return Err(CTraderError::InvalidOrder(format!(
+                    "{} must be a positive price, got {}",
+                    field, price
+                )));
+            }
+        }
+
+        let distances = [
+            ("slippage_in_points", self.slippage_in_points.map(i64::from)),
+            ("relative_stop_loss", self.relative_stop_loss),
+            ("relative_take_profit", self.relative_take_profit),
+        ];
+        for (field, distance) in distances {
+            if let Some(distance) = distance
+                && distance < 0
+            {
+                return Err(CTraderError::InvalidOrder(format!(
+                    "{} must not be negative, got {}",
+                    field, distance
+                )));
+            }
+        }
+
+        let texts = [
+            ("comment", &self.comment, MAX_COMMENT_LEN),
+            ("label", &self.label, MAX_LABEL_LEN),
+            (
+                "client_order_id",
+                &self.client_order_id,
+                MAX_CLIENT_ORDER_ID_LEN,
+            ),
+        ];
+        for (field, text, max_len) in texts {
+            if let Some(text) = text
+                && text.chars().count() > max_len
+            {
+                return Err(CTraderError::InvalidOrder(format!(
+                    "{} is longer than {} characters",
+                    field, max_len
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fail when `field` is set on an order type that does not use it, or missing on one that
+    /// requires it
+    fn check_field(
+        &self,
+        field: &str,
+        is_set: bool,
+        used_by: &[ProtoOaOrderType],
+        required: bool,
+    ) -> CTraderResult<()> {
+        let is_used = used_by.contains(&self.order_type);
+
+        if is_set && !is_used {
+            return Err(CTraderError::InvalidOrder(format!(
+                "{} is not supported by {} orders",
+                field,
+                self.order_type.as_str_name()
+            )));
+        }
+
+        if required && is_used && !is_set {
+            return Err(CTraderError::InvalidOrder(format!(
+                "{} orders require {}",
+                self.order_type.as_str_name(),
+                field
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validate the order and build the request placing it on the account
+    pub fn to_request(&self, account_id: i64) -> CTraderResult<ProtoOaNewOrderReq> {
+        self.validate()?;
+
+        Ok(ProtoOaNewOrderReq {
+            ctid_trader_account_id: account_id,
+            symbol_id: self.symbol_id,
+            order_type: self.order_type as i32,
+            trade_side: self.trade_side as i32,
+            volume: self.volume,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+            time_in_force: self.time_in_force.map(|time_in_force| time_in_force as i32),
+            expiration_timestamp: self.expiration_timestamp,
+            stop_loss: self.stop_loss,
+            take_profit: self.take_profit,
+            comment: self.comment.clone(),
+            base_slippage_price: self.base_slippage_price,
+            slippage_in_points: self.slippage_in_points,
+            label: self.label.clone(),
+            position_id: self.position_id,
+            client_order_id: self.client_order_id.clone(),
+            relative_stop_loss: self.relative_stop_loss,
+            relative_take_profit: self.relative_take_profit,
+            guaranteed_stop_loss: self.guaranteed_stop_loss,
+            trailing_stop_loss: self.trailing_stop_loss,
+            stop_trigger_method: self.stop_trigger_method.map(|method| method as i32),
+            ..Default::default()
+        })
+    }
+}
+
+impl AmendOrder {
+    /// Build the request amending an order of the account
+    pub fn to_request(&self, account_id: i64, order_id: i64) -> ProtoOaAmendOrderReq {
+        ProtoOaAmendOrderReq {
+            ctid_trader_account_id: account_id,
+            order_id,
+            volume: self.volume,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+            expiration_timestamp: self.expiration_timestamp,
+            stop_loss: self.stop_loss,
+            take_profit: self.take_profit,
+            slippage_in_points: self.slippage_in_points,
+            relative_stop_loss: self.relative_stop_loss,
+            relative_take_profit: self.relative_take_profit,
+            guaranteed_stop_loss: self.guaranteed_stop_loss,
+            trailing_stop_loss: self.trailing_stop_loss,
+            stop_trigger_method: self.stop_trigger_method.map(|method| method as i32),
+            ..Default::default()
+        }
+    }
+}
+
+impl CTraderClient {
+    /// Validate and send a new order for the account
+    pub async fn send_new_order(
+        &self,
+        account_id: i64,
+        order: &OrderRequest,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let req = order.to_request(account_id)?;
+
+        self.request(&req).await
+    }
+
+    /// Send a new MARKET_RANGE order, filled only within `slippage_in_points` of
+    /// `base_slippage_price`
+    pub async fn send_new_market_range_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        base_slippage_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let order = OrderRequest::market_range(
+            symbol_id,
+            trade_side,
+            volume,
+            base_slippage_price,
+            slippage_in_points,
+        );
+
+        self.send_new_order(account_id, &order).await
+    }
+
+    /// Send a new STOP_LIMIT order, triggered at `stop_price` and filled only within
+    /// `slippage_in_points` of it
+    pub async fn send_new_stop_limit_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        stop_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        let order = OrderRequest::stop_limit(
+            symbol_id,
+            trade_side,
+            volume,
+            stop_price,
+            slippage_in_points,
+        );
+
+        self.send_new_order(account_id, &order).await
+    }
+}
+
+impl AccountSession {
+    /// Validate and send a new order for the account
+    pub async fn send_new_order(
+        &self,
+        order: &OrderRequest,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client.send_new_order(self.account_id, order).await
+    }
+
+    pub async fn send_new_market_range_order(
+        &self,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        base_slippage_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_new_market_range_order(
+                self.account_id,
+                symbol_id,
+                trade_side,
+                volume,
+                base_slippage_price,
+                slippage_in_points,
+            )
+            .await
+    }
+
+    pub async fn send_new_stop_limit_order(
+        &self,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        stop_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        self.client
+            .send_new_stop_limit_order(
+                self.account_id,
+                symbol_id,
+                trade_side,
+                volume,
+                stop_price,
+                slippage_in_points,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_request_sets_every_field() -> anyhow::Result<()> {
+        let order = OrderRequest::stop(41, ProtoOaTradeSide::Sell, 1000, 1.2)
+            .expiration_timestamp(1_700_000_000_000)
+            .stop_loss(1.25)
+            .relative_take_profit(5_000)
+            .trailing_stop_loss(true)
+            .guaranteed_stop_loss(false)
+            .stop_trigger_method(ProtoOaOrderTriggerMethod::Opposite)
+            .comment("comment")
+            .label("label")
+            .client_order_id("order-1")
+            .position_id(9);
+
+        let req = order.to_request(7)?;
+
+        assert_eq!(req.ctid_trader_account_id, 7);
+        assert_eq!(req.symbol_id, 41);
+        assert_eq!(req.order_type, ProtoOaOrderType::Stop as i32);
+        assert_eq!(req.trade_side, ProtoOaTradeSide::Sell as i32);
+        assert_eq!(req.stop_price, Some(1.2));
+        assert_eq!(
+            req.time_in_force,
+            Some(ProtoOaTimeInForce::GoodTillDate as i32)
+        );
+        assert_eq!(req.expiration_timestamp, Some(1_700_000_000_000));
+        assert_eq!(req.stop_loss, Some(1.25));
+        assert_eq!(req.relative_take_profit, Some(5_000));
+        assert_eq!(req.trailing_stop_loss, Some(true));
+        assert_eq!(
+            req.stop_trigger_method,
+            Some(ProtoOaOrderTriggerMethod::Opposite as i32)
+        );
+        assert_eq!(req.client_order_id.as_deref(), Some("order-1"));
+        assert_eq!(req.position_id, Some(9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_market_range_and_stop_limit_orders() -> anyhow::Result<()> {
+        let buy = ProtoOaTradeSide::Buy;
+
+        let req = OrderRequest::market_range(41, buy, 1000, 1.1, 25)
+            .relative_stop_loss(100)
+            .to_request(7)?;
+        assert_eq!(req.order_type, ProtoOaOrderType::MarketRange as i32);
+        assert_eq!(req.base_slippage_price, Some(1.1));
+        assert_eq!(req.slippage_in_points, Some(25));
+        assert_eq!(req.stop_price, None);
+
+        let req = OrderRequest::stop_limit(41, buy, 1000, 1.2, 10)
+            .stop_loss(1.15)
+            .to_request(7)?;
+        assert_eq!(req.order_type, ProtoOaOrderType::StopLimit as i32);
+        assert_eq!(req.stop_price, Some(1.2));
+        assert_eq!(req.slippage_in_points, Some(10));
+
+        // Slippage is a distance, the market range base price is not a stop price
+        assert!(
+            OrderRequest::market_range(41, buy, 1000, 1.1, -1)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::market_range(41, buy, 1000, 1.1, 25)
+                .stop_loss(1.0)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::market_range(41, buy, 1000, 1.1, 25)
+                .stop_price(1.2)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::new(41, ProtoOaOrderType::StopLimit, buy, 1000)
+                .stop_price(1.2)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::stop_limit(41, buy, 1000, 1.2, 10)
+                .base_slippage_price(1.1)
+                .validate()
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_order_validation_per_type() {
+        let buy = ProtoOaTradeSide::Buy;
+
+        // Market orders take relative levels only, and no slippage
+        assert!(OrderRequest::market(41, buy, 1000).validate().is_ok());
+        assert!(
+            OrderRequest::market(41, buy, 1000)
+                .relative_stop_loss(100)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            OrderRequest::market(41, buy, 1000)
+                .stop_loss(1.1)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::market(41, buy, 1000)
+                .slippage_in_points(10)
+                .validate()
+                .is_err()
+        );
+
+        // Prices must match the order type
+        assert!(
+            OrderRequest::new(41, ProtoOaOrderType::Limit, buy, 1000)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::limit(41, buy, 1000, 1.1)
+                .stop_price(1.2)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::limit(41, buy, 1000, 1.1)
+                .slippage_in_points(10)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::new(41, ProtoOaOrderType::MarketRange, buy, 1000)
+                .slippage_in_points(10)
+                .validate()
+                .is_err()
+        );
+
+        // Fields that contradict each other
+        assert!(
+            OrderRequest::limit(41, buy, 1000, 1.1)
+                .stop_loss(1.0)
+                .relative_stop_loss(100)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::limit(41, buy, 1000, 1.1)
+                .trailing_stop_loss(true)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            OrderRequest::limit(41, buy, 1000, 1.1)
+                .time_in_force(ProtoOaTimeInForce::GoodTillDate)
+                .validate()
+                .is_err()
+        );
+        assert!(OrderRequest::market(41, buy, 0).validate().is_err());
+        assert!(
+            OrderRequest::limit(41, buy, 1000, 1.1)
+                .label("x".repeat(101))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_amend_order_only_sets_the_changes() {
+        let amend = AmendOrder {
+            volume: Some(2000),
+            stop_trigger_method: Some(ProtoOaOrderTriggerMethod::Opposite),
+            ..Default::default()
+        };
+
+        let req = amend.to_request(7, 99);
+
+        assert_eq!(req.ctid_trader_account_id, 7);
+        assert_eq!(req.order_id, 99);
+        assert_eq!(req.volume, Some(2000));
+        assert_eq!(
+            req.stop_trigger_method,
+            Some(ProtoOaOrderTriggerMethod::Opposite as i32)
+        );
+        assert_eq!(req.limit_price, None);
+        assert_eq!(req.stop_loss, None);
+    }
+
+    #[test]
+    fn test_expiration_keeps_explicit_time_in_force() {
+        let ioc = ProtoOaTimeInForce::ImmediateOrCancel;
+
+        let order = OrderRequest::limit(41, ProtoOaTradeSide::Buy, 1000, 1.1)
+            .time_in_force(ioc)
+            .expiration_timestamp(1_700_000_000_000);
+        assert_eq!(order.time_in_force, Some(ioc));
+        assert!(matches!(
+            order.validate(),
+            Err(CTraderError::InvalidOrder(_))
+        ));
+
+        let order = OrderRequest::limit(41, ProtoOaTradeSide::Buy, 1000, 1.1)
+            .expiration_timestamp(1_700_000_000_000)
+            .time_in_force(ioc);
+        assert!(matches!(
+            order.validate(),
+            Err(CTraderError::InvalidOrder(_))
+        ));
+
+        let order = OrderRequest::limit(41, ProtoOaTradeSide::Buy, 1000, 1.1)
+            .expiration_timestamp(1_700_000_000_000);
+        assert_eq!(order.time_in_force, Some(ProtoOaTimeInForce::GoodTillDate));
+        assert!(order.validate().is_ok());
+    }
+}
@@ -0,0 +1,184 @@
+use super::traits::Payload;
+use crate::openapi::*;
+
+/// Implement `Payload` for each message, taking the id from the payload type enum
+macro_rules! payloads {
+    ($kind:ident { $($message:ident => $variant:ident,)* }) => {
+        $(
+            impl Payload for $message {
+                const PAYLOAD_TYPE: u32 = $kind::$variant as u32;
+            }
+        )*
+
+        #[cfg(test)]
+        impl $kind {
+            /// Every message sent with a variant of this enum, with its Rust type name
+            pub(crate) const MESSAGES: &[(&str, u32)] = &[
+                $((stringify!($message), $message::PAYLOAD_TYPE),)*
+            ];
+        }
+    };
+}
+
+payloads!(ProtoPayloadType {
+    ProtoErrorRes => ErrorRes,
+    ProtoHeartbeatEvent => HeartbeatEvent,
+});
+
+payloads!(ProtoOaPayloadType {
+    ProtoOaApplicationAuthReq => ProtoOaApplicationAuthReq,
+    ProtoOaApplicationAuthRes => ProtoOaApplicationAuthRes,
+    ProtoOaAccountAuthReq => ProtoOaAccountAuthReq,
+    ProtoOaAccountAuthRes => ProtoOaAccountAuthRes,
+    ProtoOaErrorRes => ProtoOaErrorRes,
+    ProtoOaClientDisconnectEvent => ProtoOaClientDisconnectEvent,
+    ProtoOaAccountsTokenInvalidatedEvent => ProtoOaAccountsTokenInvalidatedEvent,
+    ProtoOaVersionReq => ProtoOaVersionReq,
+    ProtoOaVersionRes => ProtoOaVersionRes,
+    ProtoOaNewOrderReq => ProtoOaNewOrderReq,
+    ProtoOaExecutionEvent => ProtoOaExecutionEvent,
+    ProtoOaCancelOrderReq => ProtoOaCancelOrderReq,
+    ProtoOaAmendOrderReq => ProtoOaAmendOrderReq,
+    ProtoOaAmendPositionSltpReq => ProtoOaAmendPositionSltpReq,
+    ProtoOaClosePositionReq => ProtoOaClosePositionReq,
+    ProtoOaTrailingSlChangedEvent => ProtoOaTrailingSlChangedEvent,
+    ProtoOaAssetListReq => ProtoOaAssetListReq,
+    ProtoOaAssetListRes => ProtoOaAssetListRes,
+    ProtoOaSymbolsListReq => ProtoOaSymbolsListReq,
+    ProtoOaSymbolsListRes => ProtoOaSymbolsListRes,
+    ProtoOaSymbolByIdReq => ProtoOaSymbolByIdReq,
+    ProtoOaSymbolByIdRes => ProtoOaSymbolByIdRes,
+    ProtoOaSymbolsForConversionReq => ProtoOaSymbolsForConversionReq,
+    ProtoOaSymbolsForConversionRes => ProtoOaSymbolsForConversionRes,
+    ProtoOaSymbolChangedEvent => ProtoOaSymbolChangedEvent,
+    ProtoOaAssetClassListReq => ProtoOaAssetClassListReq,
+    ProtoOaAssetClassListRes => ProtoOaAssetClassListRes,
+    ProtoOaTraderReq => ProtoOaTraderReq,
+    ProtoOaTraderRes => ProtoOaTraderRes,
+    ProtoOaTraderUpdatedEvent => ProtoOaTraderUpdateEvent,
+    ProtoOaReconcileReq => ProtoOaReconcileReq,
+    ProtoOaReconcileRes => ProtoOaReconcileRes,
+    ProtoOaOrderErrorEvent => ProtoOaOrderErrorEvent,
+    ProtoOaDealListReq => ProtoOaDealListReq,
+    ProtoOaDealListRes => ProtoOaDealListRes,
+    ProtoOaOrderListReq => ProtoOaOrderListReq,
+    ProtoOaOrderListRes => ProtoOaOrderListRes,
+    ProtoOaExpectedMarginReq => ProtoOaExpectedMarginReq,
+    ProtoOaExpectedMarginRes => ProtoOaExpectedMarginRes,
+    ProtoOaMarginChangedEvent => ProtoOaMarginChangedEvent,
+    ProtoOaCashFlowHistoryListReq => ProtoOaCashFlowHistoryListReq,
+    ProtoOaCashFlowHistoryListRes => ProtoOaCashFlowHistoryListRes,
+    ProtoOaGetAccountListByAccessTokenReq => ProtoOaGetAccountsByAccessTokenReq,
+    ProtoOaGetAccountListByAccessTokenRes => ProtoOaGetAccountsByAccessTokenRes,
+    ProtoOaRefreshTokenReq => ProtoOaRefreshTokenReq,
+    ProtoOaRefreshTokenRes => ProtoOaRefreshTokenRes,
+    ProtoOaSubscribeSpotsReq => ProtoOaSubscribeSpotsReq,
+    ProtoOaSubscribeSpotsRes => ProtoOaSubscribeSpotsRes,
+    ProtoOaUnsubscribeSpotsReq => ProtoOaUnsubscribeSpotsReq,
+    ProtoOaUnsubscribeSpotsRes => ProtoOaUnsubscribeSpotsRes,
+    ProtoOaSpotEvent => ProtoOaSpotEvent,
+    ProtoOaSubscribeLiveTrendbarReq => ProtoOaSubscribeLiveTrendbarReq,
+    ProtoOaSubscribeLiveTrendbarRes => ProtoOaSubscribeLiveTrendbarRes,
+    ProtoOaUnsubscribeLiveTrendbarReq => ProtoOaUnsubscribeLiveTrendbarReq,
+    ProtoOaUnsubscribeLiveTrendbarRes => ProtoOaUnsubscribeLiveTrendbarRes,
+    ProtoOaGetTrendbarsReq => ProtoOaGetTrendbarsReq,
+    ProtoOaGetTrendbarsRes => ProtoOaGetTrendbarsRes,
+    ProtoOaGetTickDataReq => ProtoOaGetTickdataReq,
+    ProtoOaGetTickDataRes => ProtoOaGetTickdataRes,
+    ProtoOaGetCtidProfileByTokenReq => ProtoOaGetCtidProfileByTokenReq,
+    ProtoOaGetCtidProfileByTokenRes => ProtoOaGetCtidProfileByTokenRes,
+    ProtoOaDepthEvent => ProtoOaDepthEvent,
+    ProtoOaSubscribeDepthQuotesReq => ProtoOaSubscribeDepthQuotesReq,
+    ProtoOaSubscribeDepthQuotesRes => ProtoOaSubscribeDepthQuotesRes,
+    ProtoOaUnsubscribeDepthQuotesReq => ProtoOaUnsubscribeDepthQuotesReq,
+    ProtoOaUnsubscribeDepthQuotesRes => ProtoOaUnsubscribeDepthQuotesRes,
+    ProtoOaSymbolCategoryListReq => ProtoOaSymbolCategoryReq,
+    ProtoOaSymbolCategoryListRes => ProtoOaSymbolCategoryRes,
+    ProtoOaAccountLogoutReq => ProtoOaAccountLogoutReq,
+    ProtoOaAccountLogoutRes => ProtoOaAccountLogoutRes,
+    ProtoOaAccountDisconnectEvent => ProtoOaAccountDisconnectEvent,
+    ProtoOaMarginCallListReq => ProtoOaMarginCallListReq,
+    ProtoOaMarginCallListRes => ProtoOaMarginCallListRes,
+    ProtoOaMarginCallUpdateReq => ProtoOaMarginCallUpdateReq,
+    ProtoOaMarginCallUpdateRes => ProtoOaMarginCallUpdateRes,
+    ProtoOaMarginCallUpdateEvent => ProtoOaMarginCallUpdateEvent,
+    ProtoOaMarginCallTriggerEvent => ProtoOaMarginCallTriggerEvent,
+    ProtoOaGetDynamicLeverageByIdReq => ProtoOaGetDynamicLeverageReq,
+    ProtoOaGetDynamicLeverageByIdRes => ProtoOaGetDynamicLeverageRes,
+    ProtoOaDealListByPositionIdReq => ProtoOaDealListByPositionIdReq,
+    ProtoOaDealListByPositionIdRes => ProtoOaDealListByPositionIdRes,
+    ProtoOaOrderDetailsReq => ProtoOaOrderDetailsReq,
+    ProtoOaOrderDetailsRes => ProtoOaOrderDetailsRes,
+    ProtoOaOrderListByPositionIdReq => ProtoOaOrderListByPositionIdReq,
+    ProtoOaOrderListByPositionIdRes => ProtoOaOrderListByPositionIdRes,
+    ProtoOaDealOffsetListReq => ProtoOaDealOffsetListReq,
+    ProtoOaDealOffsetListRes => ProtoOaDealOffsetListRes,
+    ProtoOaGetPositionUnrealizedPnLReq => ProtoOaGetPositionUnrealizedPnlReq,
+    ProtoOaGetPositionUnrealizedPnLRes => ProtoOaGetPositionUnrealizedPnlRes,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Find every message in a proto file together with the default of its `payloadType`
+    fn declared_payload_types(proto: &str) -> Vec<(String, String)> {
+        let mut declared = Vec::new();
+        let mut message = None;
+
+        for line in proto.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("message ") {
+                message = rest.split_whitespace().next().map(str::to_string);
+            } else if line.contains(" payloadType = 1 ")
+                && let (Some(message), Some((_, default))) =
+                    (message.take(), line.split_once("[default = "))
+            {
+                declared.push((message, default.trim_end_matches("];").to_string()));
+            }
+        }
+
+        declared
+    }
+
+    /// Check every message declared in `proto` is mapped to the id its `payloadType` defaults to
+    fn check_mapping(
+        proto: &str,
+        messages: &[(&str, u32)],
+        from_str_name: impl Fn(&str) -> Option<i32>,
+    ) {
+        let declared = declared_payload_types(proto);
+
+        assert_eq!(declared.len(), messages.len());
+
+        for (message, default) in declared {
+            // prost only changes the case of acronyms, e.g. ProtoOAErrorRes to ProtoOaErrorRes
+            let (_, payload_type) = messages
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(&message))
+                .unwrap_or_else(|| panic!("{} does not implement Payload", message));
+
+            let expected = from_str_name(&default)
+                .unwrap_or_else(|| panic!("{} is not a payload type", default));
+
+            assert_eq!(*payload_type, expected as u32, "{}", message);
+        }
+    }
+
+    #[test]
+    fn test_oa_payload_types_match_proto() {
+        check_mapping(
+            include_str!("../../protos/OpenApiMessages.proto"),
+            ProtoOaPayloadType::MESSAGES,
+            |name| ProtoOaPayloadType::from_str_name(name).map(|kind| kind as i32),
+        );
+    }
+
+    #[test]
+    fn test_common_payload_types_match_proto() {
+        check_mapping(
+            include_str!("../../protos/OpenApiCommonMessages.proto"),
+            ProtoPayloadType::MESSAGES,
+            |name| ProtoPayloadType::from_str_name(name).map(|kind| kind as i32),
+        );
+    }
+}
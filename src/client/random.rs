@@ -0,0 +1,19 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Distinguishes calls made with the same random keys
+static CALLS: AtomicU64 = AtomicU64::new(0);
+
+/// A random number from the randomly keyed hasher of the standard library.
+/// Good enough for jitter, not meant for cryptography or anything that must be unguessable.
+pub(crate) fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(CALLS.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
+/// A random number in `[0, 1]`
+pub(crate) fn random_unit() -> f64 {
+    random_u64() as f64 / u64::MAX as f64
+}
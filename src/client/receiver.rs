@@ -1,39 +1,293 @@
+use super::codec::{self, Frame};
+use crate::error::CTraderResult;
+use crate::openapi::{ProtoOaPayloadType, ProtoPayloadType};
+use crate::types::{Event, PendingRequests, Session};
 use futures_util::stream::{SplitStream, StreamExt};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Notify, broadcast};
 use tokio::{net::TcpStream, sync::Mutex};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, tungstenite::Message};
 
+impl Event {
+    /// Decode the event carried by a frame.
+    /// Returns `None` when the payload type is a response rather than an event.
+    pub(crate) fn from_frame(frame: &Frame) -> CTraderResult<Option<Self>> {
+        if frame.payload_type == ProtoPayloadType::HeartbeatEvent as u32 {
+            return Ok(Some(Event::Heartbeat));
+        }
+
+        if frame.payload_type == ProtoPayloadType::ErrorRes as u32 {
+            return Ok(Some(Event::CommonError(frame.payload()?)));
+        }
+
+        let Ok(payload_type) = ProtoOaPayloadType::try_from(frame.payload_type as i32) else {
+            return Ok(None);
+        };
+
+        let event = match payload_type {
+            ProtoOaPayloadType::ProtoOaSpotEvent => Event::Spot(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaExecutionEvent => Event::Execution(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaDepthEvent => Event::Depth(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaOrderErrorEvent => Event::OrderError(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaTrailingSlChangedEvent => {
+                Event::TrailingStopLossChanged(frame.payload()?)
+            }
+            ProtoOaPayloadType::ProtoOaMarginChangedEvent => Event::MarginChanged(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaMarginCallUpdateEvent => {
+                Event::MarginCallUpdated(frame.payload()?)
+            }
+            ProtoOaPayloadType::ProtoOaMarginCallTriggerEvent => {
+                Event::MarginCallTriggered(frame.payload()?)
+            }
+            ProtoOaPayloadType::ProtoOaSymbolChangedEvent => Event::SymbolChanged(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaTraderUpdateEvent => Event::TraderUpdated(frame.payload()?),
+            ProtoOaPayloadType::ProtoOaAccountDisconnectEvent => {
+                Event::AccountDisconnected(frame.payload()?)
+            }
+            ProtoOaPayloadType::ProtoOaClientDisconnectEvent => {
+                Event::ClientDisconnected(frame.payload()?)
+            }
+            ProtoOaPayloadType::ProtoOaAccountsTokenInvalidatedEvent => {
+                Event::AccountsTokenInvalidated(frame.payload()?)
+            }
+            ProtoOaPayloadType::ProtoOaErrorRes => Event::Error(frame.payload()?),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+/// Hand a frame to the request awaiting it, or publish it as an event
+async fn dispatch(
+    frame: Frame,
+    pending: &PendingRequests,
+    events: &broadcast::Sender<Event>,
+    session: &Mutex<Session>,
+    heartbeat_due: &Notify,
+) {
+    // Hand responses back to the request awaiting them
+    let waiter = match frame.client_msg_id.as_ref() {
+        Some(id) => pending.lock().await.remove(id),
+        None => None,
+    };
+
+    if let Some(waiter) = waiter {
+        // Execution events answering a request are also published to subscribers
+        let is_execution = frame.payload_type == ProtoOaPayloadType::ProtoOaExecutionEvent as u32;
+        let published = is_execution.then(|| Event::from_frame(&frame));
+
+        let _ = waiter.send(frame);
+
+        if let Some(Ok(Some(event))) = published {
+            let _ = events.send(event);
+        }
+        return;
+    }
+
+    match Event::from_frame(&frame) {
+        Ok(Some(Event::Heartbeat)) => {
+            // Answer the server so it keeps the connection open
+            heartbeat_due.notify_one();
+            let _ = events.send(Event::Heartbeat);
+        }
+        Ok(Some(event)) => {
+            // The account has to be authorized again, so it must not be restored on reconnect
+            if let Event::AccountDisconnected(disconnect) = &event {
+                tracing::warn!(
+                    "Account {} was disconnected by the server",
+                    disconnect.ctid_trader_account_id
+                );
+                session
+                    .lock()
+                    .await
+                    .forget_account(disconnect.ctid_trader_account_id);
+            }
+
+            // Sending only fails when nobody is subscribed
+            let _ = events.send(event);
+        }
+        Ok(None) => {
+            tracing::info!(
+                "Unsolicited message with payload type {}",
+                frame.payload_type
+            );
+        }
+        Err(e) => {
+            tracing::error!("Error decoding payload type {}: {}", frame.payload_type, e);
+        }
+    }
+}
+
+/// Read and dispatch frames until the connection is closed or stays silent for too long.
+/// * idle_timeout - Give up on the connection when no frame arrived for this long, which
+///   catches half-open TCP connections that would otherwise never report an error
 pub async fn on_message(
     incoming: Arc<Mutex<SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>>>,
+    pending: PendingRequests,
+    events: broadcast::Sender<Event>,
+    session: Arc<Mutex<Session>>,
+    heartbeat_due: Arc<Notify>,
+    idle_timeout: Duration,
 ) {
     // let incoming = incoming.clone();
 
     tracing::info!("Async Client Listening to Ctrader Messages");
 
-    while let Some(msg) = incoming.lock().await.next().await {
+    loop {
+        let next = tokio::time::timeout(idle_timeout, async { incoming.lock().await.next().await });
+
+        let msg = match next.await {
+            Ok(Some(msg)) => msg,
+            Ok(None) => break,
+            Err(_) => {
+                tracing::warn!("No message received for {:?}, reconnecting", idle_timeout);
+                let _ = events.send(Event::ConnectionUnhealthy {
+                    reason: format!("no message received for {:?}", idle_timeout),
+                });
+                break;
+            }
+        };
+
         match msg {
-            Ok(msg) => {
-                match msg {
-                    Message::Text(text) => {
-                        tracing::info!("Text message: {}", text);
-                    }
-                    Message::Binary(data) => {
-                        tracing::info!("Binary message: {} bytes", data.len());
-                        // If it's protobuf, you'd decode it here
-                    }
-                    Message::Close(_) => {
-                        tracing::warn!("Connection closed");
-                        break;
-                    }
-                    _ => {
-                        tracing::info!("Other message type");
-                    }
+            Ok(msg) => match msg {
+                Message::Text(text) => {
+                    match codec::decode_json(&text) {
+                        Ok(frame) => {
+                            dispatch(frame, &pending, &events, &session, &heartbeat_due).await
+                        }
+                        // The text may carry tokens, keep it out of the logs
+                        Err(e) => {
+                            tracing::error!("Error decoding message of {} bytes: {}", text.len(), e)
+                        }
+                    };
                 }
-            }
+                Message::Binary(data) => {
+                    match codec::decode(&data) {
+                        Ok(frame) => {
+                            dispatch(frame, &pending, &events, &session, &heartbeat_due).await
+                        }
+                        Err(e) => tracing::error!("Error decoding message: {}", e),
+                    };
+                }
+                Message::Close(_) => {
+                    tracing::warn!("Connection closed");
+                    break;
+                }
+                _ => {
+                    tracing::info!("Other message type");
+                }
+            },
             Err(e) => {
                 tracing::error!("Error reading message: {}", e);
                 continue;
             }
         }
     }
+
+    // Dropping the senders wakes every request still waiting for a response
+    pending.lock().await.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::{ProtoErrorRes, ProtoOaSpotEvent, ProtoOaTraderRes};
+
+    #[test]
+    fn test_decode_spot_event() -> anyhow::Result<()> {
+        let spot = ProtoOaSpotEvent {
+            ctid_trader_account_id: 7,
+            symbol_id: 41,
+            bid: Some(108_000),
+            ..Default::default()
+        };
+        let frame = codec::encode(&spot, None);
+        let frame = codec::decode(&frame.into_data())?;
+
+        match Event::from_frame(&frame)? {
+            Some(Event::Spot(event)) => assert_eq!(event, spot),
+            other => panic!("expected a spot event, got {:?}", other),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_heartbeat_event() -> anyhow::Result<()> {
+        let frame = codec::decode_json(r#"{"payloadType":51,"payload":{}}"#)?;
+
+        assert!(matches!(Event::from_frame(&frame)?, Some(Event::Heartbeat)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_common_error() -> anyhow::Result<()> {
+        let error = ProtoErrorRes {
+            error_code: "CANT_ROUTE_REQUEST".into(),
+            ..Default::default()
+        };
+        let frame = codec::encode(&error, None);
+        let frame = codec::decode(&frame.into_data())?;
+
+        match Event::from_frame(&frame)? {
+            Some(Event::CommonError(event)) => assert_eq!(event, error),
+            other => panic!("expected a common error, got {:?}", other),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_responses_are_not_events() -> anyhow::Result<()> {
+        let frame = codec::encode(&ProtoOaTraderRes::default(), None);
+        let frame = codec::decode(&frame.into_data())?;
+
+        assert!(Event::from_frame(&frame)?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_silent_connection_is_given_up() -> anyhow::Result<()> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        // Accept the connection and never send anything, like a half-open connection
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            let ws_stream = tokio_tungstenite::accept_async(stream).await?;
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            drop(ws_stream);
+            anyhow::Ok(())
+        });
+
+        let (ws_stream, _) = tokio_tungstenite::connect_async(url).await?;
+        let (_ws_write, ws_read) = ws_stream.split();
+        let (events, mut received) = broadcast::channel(8);
+
+        tokio::time::timeout(
+            Duration::from_secs(2),
+            on_message(
+                Arc::new(Mutex::new(ws_read)),
+                PendingRequests::default(),
+                events,
+                Arc::default(),
+                Arc::default(),
+                Duration::from_millis(100),
+            ),
+        )
+        .await?;
+
+        assert!(matches!(
+            received.try_recv()?,
+            Event::ConnectionUnhealthy { .. }
+        ));
+
+        server_handle.abort();
+
+        Ok(())
+    }
 }
@@ -0,0 +1,352 @@
+use super::endpoint::Endpoints;
+use crate::error::{CTraderError, CTraderResult};
+use crate::types::{Auth, TokenResponse};
+use reqwest::Url;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+
+/// Largest request head read from the browser
+const MAX_REQUEST_LEN: usize = 16 * 1024;
+
+/// How long a connection may take to send its request head before it is dropped
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl Auth {
+    /// Build the consent URL, passing `state` back to the redirect URL
+    pub fn get_auth_uri_with_state(&self, state: &str) -> CTraderResult<String> {
+        let uri = Url::parse_with_params(
+            Endpoints::AUTH_URI,
+            &[
+                ("client_id", self.ctrader_client_id.as_str()),
+                ("redirect_uri", self.ctrader_redirect_url.as_str()),
+                ("scope", "trading"),
+                ("state", state),
+            ],
+        )
+        .map_err(|e| CTraderError::Other(format!("invalid auth URI: {}", e)))?;
+
+        Ok(uri.to_string())
+    }
+
+    /// Authorize a trading account from the command line.
+    /// Listens on the host and port of `ctrader_redirect_url`, prints the consent URL to open in
+    /// a browser, waits for the redirect and exchanges the code it carries for tokens.
+    /// The redirect URL must point to this machine, e.g. `http://localhost:8080/callback`.
+    /// * timeout - How long to wait for the user to complete the consent in the browser
+    pub async fn authorize_with_redirect_listener(
+        &mut self,
+        timeout: Duration,
+    ) -> CTraderResult<TokenResponse> {
+        let redirect = Url::parse(&self.ctrader_redirect_url)
+            .map_err(|e| CTraderError::Other(format!("invalid redirect URL: {}", e)))?;
+
+        let host = match redirect.host_str() {
+            Some(host @ ("localhost" | "127.0.0.1" | "[::1]")) if redirect.scheme() == "http" => {
+                host.trim_start_matches('[').trim_end_matches(']')
+            }
+            _ => {
+                return Err(CTraderError::Other(format!(
+                    "redirect URL {} is not a local http URL",
+                    redirect
+                )));
+            }
+        };
+        let port = redirect.port_or_known_default().unwrap_or(80);
+
+        let listener = TcpListener::bind((host, port)).await?;
+
+        let state = random_state()?;
+        let auth_uri = self.get_auth_uri_with_state(&state)?;
+
+        tracing::info!("Waiting for the authorization redirect on {}", redirect);
+        println!(
+            "Open this URL in a browser to authorize the application:\n{}",
+            auth_uri
+        );
+
+        let code = tokio::time::timeout(timeout, wait_for_redirect(&listener, &redirect, &state))
+            .await
+            .map_err(|_| CTraderError::TimeoutError {
+                task: "authorization redirect".into(),
+                duration: timeout,
+            })??;
+
+        self.get_token(&code).await
+    }
+}
+
+/// Generate an unguessable value tying the redirect to the consent URL we printed, using the
+/// random number generator of the operating system
+fn random_state() -> CTraderResult<String> {
+    let mut bytes = [0; 16];
+    getrandom::fill(&mut bytes).map_err(|e| {
+        CTraderError::Other(format!("unable to generate the authorization state: {}", e))
+    })?;
+
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Accept connections until the browser is redirected to `redirect`, then return its code.
+/// Each connection is read in its own task, so an idle one such as a browser preconnect does
+/// not hold up the others. Other paths, e.g. the favicon, get a 404 and a redirect with the
+/// wrong state a 400 so the browser moves on.
+async fn wait_for_redirect(
+    listener: &TcpListener,
+    redirect: &Url,
+    state: &str,
+) -> CTraderResult<String> {
+    let mut connections = JoinSet::new();
+
+    loop {
+        let (mut stream, target) = tokio::select! {
+            accepted = listener.accept() => {
+                let (mut stream, _) = accepted?;
+                connections.spawn(async move {
+                    let target =
+                        tokio::time::timeout(READ_TIMEOUT, read_request_target(&mut stream)).await;
+                    (stream, target)
+                });
+                continue;
+            }
+            Some(read) = connections.join_next() => read
+                .map_err(|e| CTraderError::Other(format!("redirect connection failed: {}", e)))?,
+        };
+
+        let target = match target {
+            Ok(Ok(Some(target))) => target,
+            Ok(Ok(None)) => continue,
+            Ok(Err(e)) => {
+                tracing::warn!("Unable to read the authorization redirect: {}", e);
+                continue;
+            }
+            Err(_) => {
+                tracing::warn!("Dropping a connection idle for {:?}", READ_TIMEOUT);
+                continue;
+            }
+        };
+
+        let url = redirect
+            .join(&target)
+            .map_err(|e| CTraderError::Other(format!("invalid redirect {}: {}", target, e)))?;
+
+        if url.path() != redirect.path() {
+            respond(&mut stream, "404 Not Found", "Not found").await;
+            continue;
+        }
+
+        let query = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        if query("state").as_deref() != Some(state) {
+            respond(
+                &mut stream,
+                "400 Bad Request",
+                "Authorization state mismatch",
+            )
+            .await;
+            tracing::warn!("Ignoring an authorization redirect with an unexpected state");
+            continue;
+        }
+
+        if let Some(error) = query("error") {
+            respond(
+                &mut stream,
+                "200 OK",
+                "Authorization was denied, you can close this window",
+            )
+            .await;
+            return Err(CTraderError::HttpRequest {
+                status: None,
+                message: "authorization was denied".into(),
+                body: None,
+                error: Some(error),
+                error_description: query("error_description"),
+            });
+        }
+
+        let Some(code) = query("code") else {
+            respond(&mut stream, "400 Bad Request", "Missing authorization code").await;
+            return Err(CTraderError::Other(
+                "the authorization redirect carried no code".into(),
+            ));
+        };
+
+        respond(
+            &mut stream,
+            "200 OK",
+            "Authorization complete, you can close this window",
+        )
+        .await;
+
+        return Ok(code);
+    }
+}
+
+/// Read the head of an HTTP request and return its target, e.g. `/callback?code=...`
+async fn read_request_target(stream: &mut TcpStream) -> CTraderResult<Option<String>> {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 || request.len() > MAX_REQUEST_LEN {
+            return Ok(None);
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+
+    match (request_line.next(), request_line.next()) {
+        (Some("GET"), Some(target)) => Ok(Some(target.to_string())),
+        _ => Ok(None),
+    }
+}
+
+/// Answer the browser with a plain text page
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\ncontent-type: text/plain; charset=utf-8\r\ncontent-length: {}\r\n\
+         connection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        tracing::warn!("Unable to answer the authorization redirect: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Send a GET request for `target` and return the status line of the response
+    async fn get(port: u16, target: &str) -> anyhow::Result<String> {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+        let request = format!("GET {} HTTP/1.1\r\nhost: localhost\r\n\r\n", target);
+        stream.write_all(request.as_bytes()).await?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+
+        Ok(response.lines().next().unwrap_or_default().to_string())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_redirect_returns_code() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let port = listener.local_addr()?.port();
+        let redirect = Url::parse(&format!("http://localhost:{}/callback", port))?;
+
+        let browser = tokio::spawn(async move {
+            let favicon = get(port, "/favicon.ico").await?;
+            let callback = get(port, "/callback?code=abc%2F1&state=xyz").await?;
+            anyhow::Ok((favicon, callback))
+        });
+
+        let code = wait_for_redirect(&listener, &redirect, "xyz").await?;
+        let (favicon, callback) = browser.await??;
+
+        assert_eq!(code, "abc/1");
+        assert_eq!(favicon, "HTTP/1.1 404 Not Found");
+        assert_eq!(callback, "HTTP/1.1 200 OK");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_redirect_rejects_state_mismatch() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let port = listener.local_addr()?.port();
+        let redirect = Url::parse(&format!("http://localhost:{}/callback", port))?;
+
+        let browser = tokio::spawn(async move {
+            let forged = get(port, "/callback?code=forged&state=forged").await?;
+            let callback = get(port, "/callback?code=abc&state=xyz").await?;
+            anyhow::Ok((forged, callback))
+        });
+
+        let code = wait_for_redirect(&listener, &redirect, "xyz").await?;
+        let (forged, callback) = browser.await??;
+
+        assert_eq!(code, "abc");
+        assert_eq!(forged, "HTTP/1.1 400 Bad Request");
+        assert_eq!(callback, "HTTP/1.1 200 OK");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_redirect_ignores_idle_connections() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let port = listener.local_addr()?.port();
+        let redirect = Url::parse(&format!("http://localhost:{}/callback", port))?;
+
+        // Like a browser preconnect, open a connection and never send a request on it
+        let _idle = TcpStream::connect(("127.0.0.1", port)).await?;
+        let browser = tokio::spawn(async move { get(port, "/callback?code=abc&state=xyz").await });
+
+        let code = tokio::time::timeout(
+            Duration::from_secs(2),
+            wait_for_redirect(&listener, &redirect, "xyz"),
+        )
+        .await??;
+
+        assert_eq!(code, "abc");
+        assert_eq!(browser.await??, "HTTP/1.1 200 OK");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_consent_times_out() {
+        let mut auth = Auth::new(
+            "id".into(),
+            String::new(),
+            "secret".into(),
+            "http://127.0.0.1:0/callback".into(),
+            String::new(),
+        );
+
+        let result = auth
+            .authorize_with_redirect_listener(Duration::from_millis(50))
+            .await;
+
+        assert!(matches!(result, Err(CTraderError::TimeoutError { .. })));
+    }
+
+    #[test]
+    fn test_auth_uri_carries_state() -> anyhow::Result<()> {
+        let auth = Auth::new(
+            "id".into(),
+            String::new(),
+            "secret".into(),
+            "http://localhost:8080/callback".into(),
+            String::new(),
+        );
+
+        let uri = auth.get_auth_uri_with_state("xyz")?;
+
+        assert_eq!(
+            uri,
+            "https://openapi.ctrader.com/apps/auth?client_id=id\
+             &redirect_uri=http%3A%2F%2Flocalhost%3A8080%2Fcallback&scope=trading&state=xyz"
+        );
+        assert_ne!(random_state()?, random_state()?);
+        assert_eq!(random_state()?.len(), 32);
+
+        Ok(())
+    }
+}
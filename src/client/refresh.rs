@@ -0,0 +1,357 @@
+use super::shutdown::stopped;
+use crate::error::{CTraderError, CTraderResult, ErrorCode};
+use crate::openapi::{ProtoOaErrorCode, ProtoOaRefreshTokenRes};
+use crate::types::{CTraderClient, Event, TokenResponse};
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::time::Instant;
+
+/// Refresh the access token this long before it expires
+const REFRESH_MARGIN: Duration = Duration::from_secs(60 * 60);
+
+/// Wait this long before trying again after a failed refresh
+const REFRESH_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+impl From<ProtoOaRefreshTokenRes> for TokenResponse {
+    fn from(res: ProtoOaRefreshTokenRes) -> Self {
+        Self {
+            access_token: Some(res.access_token.into()),
+            refresh_token: Some(res.refresh_token.into()),
+            // A lifetime of zero or less means the expiry is unknown
+            expires_in: u64::try_from(res.expires_in)
+                .ok()
+                .filter(|expires_in| *expires_in > 0),
+            token_type: Some(res.token_type),
+            error: None,
+            error_description: None,
+        }
+    }
+}
+
+/// When to refresh a token expiring at `expires_at`, leaving the margin or half the remaining
+/// lifetime for short lived tokens
+fn refresh_deadline(expires_at: SystemTime, now: SystemTime) -> Duration {
+    let remaining = expires_at.duration_since(now).unwrap_or_default();
+
+    remaining - REFRESH_MARGIN.min(remaining / 2)
+}
+
+/// The instant to refresh a token expiring at `expires_at`, `None` when it is too far in the
+/// future to be represented
+fn refresh_instant(expires_at: SystemTime) -> Option<Instant> {
+    Instant::now().checked_add(refresh_deadline(expires_at, SystemTime::now()))
+}
+
+impl CTraderClient {
+    /// Refresh the access token, swap the new tokens into `auth`, save them to the token store
+    /// and re-authorize every account with them. Publishes `Event::TokensRotated` once done.
+    pub async fn refresh_tokens(&self) -> CTraderResult<()> {
+        let res: TokenResponse = self.send_refresh_token_request().await?.into();
+
+        let auth = {
+            let mut auth = self.auth.lock().await;
+            auth.update(&res);
+            // Without a lifetime the previous, already past, expiry would trigger the next
+            // refresh right away, so only refresh again on invalidation
+            if res.expires_in.is_none() {
+                auth.ctrader_token_expires_at = None;
+            }
+            auth.clone()
+        };
+
+        // The previous refresh token no longer works, losing the new one would lock us out
+        if let Some(store) = &self.token_store
+            && let Err(e) = store.save(&auth).await
+        {
+            tracing::error!("Unable to save the refreshed CTrader tokens: {}", e);
+        }
+
+        let accounts = self.session.lock().await.accounts.clone();
+        for account_id in accounts {
+            match self.send_set_account_request(account_id).await {
+                Ok(_) => {}
+                Err(CTraderError::Api {
+                    code: ErrorCode::OpenApi(ProtoOaErrorCode::AlreadyLoggedIn),
+                    ..
+                }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        tracing::info!("Refreshed the CTrader access token");
+
+        let _ = self.events.send(Event::TokensRotated {
+            access_token: auth.ctrader_access_token,
+            refresh_token: auth.ctrader_refresh_token,
+            expires_at: auth.ctrader_token_expires_at,
+        });
+
+        Ok(())
+    }
+
+    /// Refresh the tokens ahead of their expiry, and right away when the server reports they
+    /// were invalidated. Tokens of unknown expiry are only refreshed on invalidation until the
+    /// first refresh, see `Auth::with_token_expires_at`. A failed refresh is tried again after
+    /// `REFRESH_RETRY_DELAY`.
+    /// Stops when the client shuts down.
+    pub(crate) async fn run_token_refresh(self, mut events: broadcast::Receiver<Event>) {
+        let mut shutdown = self.shutdown.subscribe();
+        let mut retry = false;
+
+        loop {
+            let expires_at = self.auth.lock().await.ctrader_token_expires_at;
+            let refresh_at = expires_at.and_then(refresh_instant);
+
+            let due = async {
+                match refresh_at {
+                    Some(refresh_at) => tokio::time::sleep_until(refresh_at).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            // A failed refresh is tried again right after its delay
+            if !retry {
+                tokio::select! {
+                    _ = due => {}
+                    _ = stopped(&mut shutdown) => return,
+                    event = events.recv() => match event {
+                        Ok(Event::AccountsTokenInvalidated(event)) => {
+                            tracing::warn!(
+                                "Access token invalidated for accounts {:?}: {}",
+                                event.ctid_trader_account_ids,
+                                event.reason.as_deref().unwrap_or_default()
+                            );
+                        }
+                        // Any other event may come with new tokens, so compute the deadline again
+                        Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return,
+                    },
+                }
+            }
+
+            retry = false;
+
+            if let Err(e) = self.refresh_tokens().await {
+                tracing::error!("Unable to refresh the CTrader access token: {}", e);
+
+                let _ = self.events.send(Event::ConnectionUnhealthy {
+                    reason: format!("unable to refresh the access token: {}", e),
+                });
+
+                tokio::select! {
+                    _ = tokio::time::sleep(REFRESH_RETRY_DELAY) => {}
+                    _ = stopped(&mut shutdown) => return,
+                }
+
+                retry = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::codec;
+    use crate::client::mock::{builder, mock_server, next_request};
+    use crate::client::traits::Payload;
+    use crate::openapi::*;
+    use futures_util::SinkExt;
+
+    #[test]
+    fn test_refresh_deadline() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        assert_eq!(refresh_deadline(now + day, now), day - REFRESH_MARGIN);
+        assert_eq!(
+            refresh_deadline(now + Duration::from_secs(600), now),
+            Duration::from_secs(300)
+        );
+        assert_eq!(refresh_deadline(now - day, now), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_far_expiry_does_not_overflow() {
+        let year = Duration::from_secs(365 * 24 * 60 * 60);
+
+        // The latest expiry the platform can represent, refreshed far ahead or never
+        for secs in [u64::MAX, i64::MAX as u64, u64::MAX / 4] {
+            if let Some(far) = SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+                && let Some(refresh_at) = refresh_instant(far)
+            {
+                assert!(refresh_at > Instant::now() + year);
+            }
+        }
+
+        assert!(refresh_instant(SystemTime::now()).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_lifetime_clears_the_expiry() -> anyhow::Result<()> {
+        // Answer the refresh with tokens of unknown lifetime
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            let frame = next_request(&mut ws_stream)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("the client closed the connection"))?;
+            let res = ProtoOaRefreshTokenRes {
+                access_token: "new-access".into(),
+                refresh_token: "new-refresh".into(),
+                expires_in: 0,
+                token_type: "bearer".into(),
+                ..Default::default()
+            };
+            ws_stream
+                .send(codec::encode(&res, frame.client_msg_id))
+                .await?;
+
+            anyhow::Ok(ws_stream)
+        })
+        .await?;
+
+        // An expiry a second ago makes the refresh task refresh right away
+        let client = builder(url)
+            .token_expires_at(SystemTime::now() - Duration::from_secs(1))
+            .connect()
+            .await?;
+        let mut events = client.subscribe();
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Event::TokensRotated { expires_at, .. } = events.recv().await? {
+                    return anyhow::Ok(expires_at);
+                }
+            }
+        })
+        .await??;
+
+        let _ws_stream = server_handle.await??;
+
+        assert_eq!(client.auth.lock().await.ctrader_token_expires_at, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidated_tokens_are_refreshed_with_retry() -> anyhow::Result<()> {
+        // Invalidate the tokens once account 7 is authorized, reject the first refresh and
+        // accept the second one
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            let mut access_tokens = Vec::new();
+            let mut refreshed_at = Vec::new();
+
+            while let Some(frame) = next_request(&mut ws_stream).await? {
+                match frame.payload_type {
+                    ProtoOaAccountAuthReq::PAYLOAD_TYPE => {
+                        let req: ProtoOaAccountAuthReq = frame.payload()?;
+                        access_tokens.push(req.access_token);
+
+                        let res = ProtoOaAccountAuthRes {
+                            ctid_trader_account_id: req.ctid_trader_account_id,
+                            ..Default::default()
+                        };
+                        ws_stream
+                            .send(codec::encode(&res, frame.client_msg_id))
+                            .await?;
+
+                        if access_tokens.len() == 2 {
+                            break;
+                        }
+
+                        let event = ProtoOaAccountsTokenInvalidatedEvent {
+                            ctid_trader_account_ids: vec![7],
+                            ..Default::default()
+                        };
+                        ws_stream.send(codec::encode(&event, None)).await?;
+                    }
+                    ProtoOaRefreshTokenReq::PAYLOAD_TYPE => {
+                        let req: ProtoOaRefreshTokenReq = frame.payload()?;
+                        assert_eq!(req.refresh_token, "refresh");
+                        refreshed_at.push(Instant::now());
+
+                        if refreshed_at.len() == 1 {
+                            let res = ProtoOaErrorRes {
+                                error_code: "INVALID_REQUEST".into(),
+                                ..Default::default()
+                            };
+                            ws_stream
+                                .send(codec::encode(&res, frame.client_msg_id))
+                                .await?;
+                        } else {
+                            let res = ProtoOaRefreshTokenRes {
+                                access_token: "new-access".into(),
+                                refresh_token: "new-refresh".into(),
+                                expires_in: 30 * 24 * 60 * 60,
+                                token_type: "bearer".into(),
+                                ..Default::default()
+                            };
+                            ws_stream
+                                .send(codec::encode(&res, frame.client_msg_id))
+                                .await?;
+                        }
+                    }
+                    // Heartbeats
+                    _ => {}
+                }
+            }
+
+            anyhow::Ok((access_tokens, refreshed_at, ws_stream))
+        })
+        .await?;
+
+        // Keep the connection from being given up while the clock is advanced
+        let client = builder(url)
+            .read_idle_timeout(Duration::from_secs(24 * 60 * 60))
+            .connect()
+            .await?;
+        let mut events = client.subscribe();
+
+        client.send_set_account_request(7).await?;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Event::ConnectionUnhealthy { .. } = events.recv().await? {
+                    return anyhow::Ok(());
+                }
+            }
+        })
+        .await??;
+
+        // The refresh task is now waiting to retry
+        tokio::time::pause();
+        tokio::time::advance(REFRESH_RETRY_DELAY).await;
+        tokio::time::resume();
+
+        let rotated = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Event::TokensRotated {
+                    access_token,
+                    refresh_token,
+                    expires_at,
+                } = events.recv().await?
+                {
+                    return anyhow::Ok((access_token, refresh_token, expires_at));
+                }
+            }
+        })
+        .await??;
+
+        let (access_tokens, refreshed_at, _ws_stream) = server_handle.await??;
+
+        assert_eq!(rotated.0.expose(), "new-access");
+        assert_eq!(rotated.1.expose(), "new-refresh");
+        assert!(rotated.2.is_some());
+
+        let auth = client.auth.lock().await.clone();
+        assert_eq!(auth.ctrader_access_token.expose(), "new-access");
+        assert_eq!(auth.ctrader_refresh_token.expose(), "new-refresh");
+
+        // The account is re-authorized over the same connection with the new token
+        assert_eq!(access_tokens, ["access", "new-access"]);
+
+        assert_eq!(refreshed_at.len(), 2);
+        assert!(refreshed_at[1] - refreshed_at[0] >= REFRESH_RETRY_DELAY);
+
+        Ok(())
+    }
+}
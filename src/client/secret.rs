@@ -0,0 +1,79 @@
+use crate::types::Secret;
+use std::fmt;
+use zeroize::Zeroize;
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The secret value, to be sent to the server or saved by a token store
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Auth;
+
+    #[test]
+    fn test_auth_debug_is_redacted() {
+        let auth = Auth::new(
+            "id".into(),
+            "access-token".into(),
+            "client-secret".into(),
+            "http://localhost".into(),
+            "refresh-token".into(),
+        );
+
+        let debug = format!("{:?}", auth);
+
+        assert!(debug.contains("\"id\""));
+        assert!(!debug.contains("access-token"));
+        assert!(!debug.contains("client-secret"));
+        assert!(!debug.contains("refresh-token"));
+        assert_eq!(auth.ctrader_client_secret.to_string(), "[REDACTED]");
+        assert_eq!(auth.ctrader_client_secret.expose(), "client-secret");
+    }
+
+    #[test]
+    fn test_secret_deserializes_transparently() -> anyhow::Result<()> {
+        let secret: Secret = serde_json::from_str("\"token\"")?;
+
+        assert_eq!(secret.expose(), "token");
+
+        Ok(())
+    }
+}
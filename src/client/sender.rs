@@ -1,83 +1,204 @@
+use super::shutdown::stopped;
+use crate::openapi::{ProtoHeartbeatEvent, ProtoPayloadType};
+use crate::types::{Event, Transport};
 use futures_util::{SinkExt, stream::SplitSink};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpStream;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify, broadcast, watch};
+use tokio::time::Instant;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, tungstenite::Message};
 
+/// Keep the connection alive with `ProtoHeartbeatEvent`s.
+/// * outgoing - The websocket sink shared with the client
+/// * last_sent - When a frame was last written to `outgoing`
+/// * heartbeat_due - Notified when the server sent a heartbeat that must be answered
+/// * events - Receives an `Event::ConnectionUnhealthy` when a heartbeat cannot be sent
+/// * transport - The wire format spoken on `outgoing`
+/// * interval - How long the connection may stay idle before a heartbeat is sent
+/// * shutdown - Stops the task once it holds `true`
 pub async fn send_heartbeat(
     outgoing: Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>,
+    last_sent: Arc<Mutex<Instant>>,
+    heartbeat_due: Arc<Notify>,
+    events: broadcast::Sender<Event>,
+    transport: Transport,
+    interval: Duration,
+    mut shutdown: watch::Receiver<bool>,
 ) {
     loop {
-        let msg = Message::Text("heartbeat".into());
+        // An interval too long to represent only answers the heartbeats of the server
+        let idle_deadline = last_sent.lock().await.checked_add(interval);
+        let idle = async {
+            match idle_deadline {
+                Some(idle_deadline) => tokio::time::sleep_until(idle_deadline).await,
+                None => std::future::pending().await,
+            }
+        };
 
-        outgoing
-            .lock()
-            .await
-            .send(msg)
-            .await
-            .expect("unable to send heartbeat");
+        tokio::select! {
+            _ = idle => {
+                // Another frame may have been sent while sleeping
+                if last_sent.lock().await.elapsed() < interval {
+                    continue;
+                }
+            }
+            _ = heartbeat_due.notified() => {}
+            _ = stopped(&mut shutdown) => return,
+        }
 
-        tokio::time::sleep(Duration::from_secs(30)).await;
+        let heartbeat = ProtoHeartbeatEvent {
+            payload_type: Some(ProtoPayloadType::HeartbeatEvent as i32),
+        };
+        let sent = match transport.encode(&heartbeat, None) {
+            Ok(msg) => outgoing.lock().await.send(msg).await.map_err(Into::into),
+            Err(e) => Err(e),
+        };
+
+        match sent {
+            Ok(()) => {
+                *last_sent.lock().await = Instant::now();
+            }
+            Err(e) => {
+                tracing::error!("Unable to send heartbeat: {}", e);
+
+                let _ = events.send(Event::ConnectionUnhealthy {
+                    reason: format!("unable to send heartbeat: {}", e),
+                });
+
+                // Back off for a full interval before trying again
+                *last_sent.lock().await = Instant::now();
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use futures_util::StreamExt;
-    use tokio_tungstenite::connect_async;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::{accept_async, connect_async};
 
     use super::*;
+    use crate::client::codec;
 
     #[tokio::test]
     async fn test_send_heartbeat() -> anyhow::Result<()> {
-        let url = "wss://echo.websockets.events";
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
 
-        let (ws_stream, _) = connect_async(url)
-            .await
-            .expect("error connecting to socket");
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            let mut ws_stream = accept_async(stream).await?;
 
-        let (outgoing, incoming) = ws_stream.split();
+            match ws_stream.next().await {
+                Some(Ok(Message::Binary(data))) => Ok(codec::decode(&data)?),
+                other => Err(anyhow::anyhow!("expected a binary frame, got {:?}", other)),
+            }
+        });
 
-        let incoming = Arc::new(Mutex::new(incoming));
+        let (ws_stream, _) = connect_async(url.as_str()).await?;
+        let (outgoing, _incoming) = ws_stream.split();
         let outgoing = Arc::new(Mutex::new(outgoing));
+        let (events, _) = broadcast::channel(16);
+        let (_shutdown, shutdown) = watch::channel(false);
 
-        let receiver_handle = tokio::spawn(async move {
-            loop {
-                while let Some(message) = incoming.lock().await.next().await {
-                    match message {
-                        Ok(msg) => {
-                            match msg {
-                                Message::Text(text) => {
-                                    tracing::info!("Text message: {}", text);
-                                }
-                                Message::Binary(data) => {
-                                    tracing::info!("Binary message: {} bytes", data.len());
-                                    // If it's protobuf, you'd decode it here
-                                }
-                                Message::Close(_) => {
-                                    tracing::warn!("Connection closed");
-                                    break;
-                                }
-                                _ => {
-                                    tracing::info!("Other message type");
-                                }
-                            }
-                        }
-                        Err(err) => {
-                            tracing::error!("Error reading message: {}", err);
-                            continue;
-                        }
-                    }
-                }
+        let heartbeat_handle = tokio::spawn(send_heartbeat(
+            outgoing,
+            Arc::new(Mutex::new(Instant::now())),
+            Arc::new(Notify::new()),
+            events,
+            Transport::Protobuf,
+            Duration::from_millis(50),
+            shutdown,
+        ));
+
+        let frame = server_handle.await??;
+        heartbeat_handle.abort();
+
+        assert_eq!(frame.payload_type, 51);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_huge_interval_still_answers_the_server() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            let mut ws_stream = accept_async(stream).await?;
+
+            match ws_stream.next().await {
+                Some(Ok(Message::Binary(data))) => Ok(codec::decode(&data)?),
+                other => Err(anyhow::anyhow!("expected a binary frame, got {:?}", other)),
             }
         });
 
-        let sender_handle = tokio::spawn(async {});
+        let (ws_stream, _) = connect_async(url.as_str()).await?;
+        let (outgoing, _incoming) = ws_stream.split();
+        let heartbeat_due = Arc::new(Notify::new());
+        let (events, _) = broadcast::channel(16);
+        let (_shutdown, shutdown) = watch::channel(false);
+
+        let heartbeat_handle = tokio::spawn(send_heartbeat(
+            Arc::new(Mutex::new(outgoing)),
+            Arc::new(Mutex::new(Instant::now())),
+            heartbeat_due.clone(),
+            events,
+            Transport::Protobuf,
+            Duration::MAX,
+            shutdown,
+        ));
+
+        heartbeat_due.notify_one();
+
+        let frame = tokio::time::timeout(Duration::from_secs(5), server_handle).await???;
+        assert!(!heartbeat_handle.is_finished());
+        heartbeat_handle.abort();
+
+        assert_eq!(frame.payload_type, 51);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_heartbeat_is_reported() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            let ws_stream = accept_async(stream).await?;
+            drop(ws_stream);
+            anyhow::Ok(())
+        });
+
+        let (ws_stream, _) = connect_async(url.as_str()).await?;
+        let (mut outgoing, _incoming) = ws_stream.split();
+        server_handle.await??;
+
+        // Closing the sink makes every following send fail
+        outgoing.close().await?;
+
+        let (events, mut receiver) = broadcast::channel(16);
+        let (_shutdown, shutdown) = watch::channel(false);
+
+        let heartbeat_handle = tokio::spawn(send_heartbeat(
+            Arc::new(Mutex::new(outgoing)),
+            Arc::new(Mutex::new(Instant::now())),
+            Arc::new(Notify::new()),
+            events,
+            Transport::Protobuf,
+            Duration::from_millis(10),
+            shutdown,
+        ));
 
-        let heartbeat_handle = tokio::spawn(send_heartbeat(outgoing));
+        let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await??;
+        heartbeat_handle.abort();
 
-        let _ = tokio::join!(receiver_handle, sender_handle, heartbeat_handle);
+        assert!(matches!(event, Event::ConnectionUnhealthy { .. }));
 
         Ok(())
     }
@@ -0,0 +1,84 @@
+use super::traits::AppState;
+use crate::types::Session;
+
+impl Session {
+    /// Drop an account together with every subscription made for it
+    pub fn forget_account(&mut self, account_id: i64) {
+        self.accounts.remove(&account_id);
+        self.spots.retain(|(account, _), _| *account != account_id);
+        self.trendbars
+            .retain(|(account, _, _)| *account != account_id);
+        self.depth.retain(|(account, _)| *account != account_id);
+    }
+
+    /// Drop a spot subscription together with the live trendbars that depend on it
+    pub fn forget_spots(&mut self, account_id: i64, symbol_id: i64) {
+        self.spots.remove(&(account_id, symbol_id));
+        self.trendbars
+            .retain(|(account, symbol, _)| (*account, *symbol) != (account_id, symbol_id));
+    }
+}
+
+impl AppState for Session {
+    fn authorized_accounts(&self) -> Vec<i64> {
+        self.accounts.iter().copied().collect()
+    }
+
+    fn spot_subscriptions(&self) -> Vec<(i64, i64)> {
+        self.spots.keys().copied().collect()
+    }
+
+    fn trendbar_subscriptions(&self) -> Vec<(i64, i64, i32)> {
+        self.trendbars.iter().copied().collect()
+    }
+
+    fn depth_subscriptions(&self) -> Vec<(i64, i64)> {
+        self.depth.iter().copied().collect()
+    }
+
+    fn is_account_authorized(&self, account_id: i64) -> bool {
+        self.accounts.contains(&account_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forget_account_drops_its_subscriptions() {
+        let mut session = Session::default();
+        session.accounts.extend([1, 2]);
+        session.spots.insert((1, 41), false);
+        session.spots.insert((2, 41), true);
+        session.trendbars.insert((1, 41, 1));
+        session.depth.insert((1, 41));
+
+        session.forget_account(1);
+
+        assert!(!session.is_account_authorized(1));
+        assert!(session.is_account_authorized(2));
+        assert_eq!(session.spot_subscriptions(), vec![(2, 41)]);
+        assert!(session.trendbar_subscriptions().is_empty());
+        assert!(session.depth_subscriptions().is_empty());
+
+        session.forget_account(2);
+
+        assert!(session.is_empty());
+    }
+
+    #[test]
+    fn test_forget_spots_drops_their_trendbars() {
+        let mut session = Session::default();
+        session.spots.insert((1, 41), false);
+        session.spots.insert((1, 42), false);
+        session.trendbars.insert((1, 41, 1));
+        session.trendbars.insert((1, 41, 5));
+        session.trendbars.insert((1, 42, 1));
+
+        session.forget_spots(1, 41);
+
+        assert_eq!(session.spot_subscriptions(), vec![(1, 42)]);
+        assert_eq!(session.trendbar_subscriptions(), vec![(1, 42, 1)]);
+    }
+}
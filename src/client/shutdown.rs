@@ -0,0 +1,250 @@
+use crate::error::{CTraderError, CTraderResult};
+use crate::openapi::ProtoOaTrendbarPeriod;
+use crate::types::{CTraderClient, ClientGuard, Session};
+use futures_util::SinkExt;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Resolve once the client shuts down, either through `CTraderClient::shutdown` or because the
+/// last handle given to the user was dropped, see `ClientGuard`
+pub(crate) async fn stopped(shutdown: &mut watch::Receiver<bool>) {
+    // Drop the borrowed value right away so the future stays `Send`
+    let _ = shutdown.wait_for(|closing| *closing).await.map(drop);
+}
+
+/// Run `future` until `deadline`, `None` when it did not complete in time.
+/// Without a deadline it runs to completion.
+async fn until<F: Future>(deadline: Option<Instant>, future: F) -> Option<F::Output> {
+    match deadline {
+        Some(deadline) => tokio::time::timeout_at(deadline, future).await.ok(),
+        None => Some(future.await),
+    }
+}
+
+impl CTraderClient {
+    /// Stop the client: unsubscribe every stream, log out every authorized account, close the
+    /// websocket and wait for the background tasks, aborting the ones still running.
+    /// All of it takes at most `timeout`, requests still unanswered by then are given up.
+    /// Every step is attempted even when an earlier one failed, the first error is returned.
+    pub async fn shutdown(&self, timeout: Duration) -> CTraderResult<()> {
+        // Tell the reader not to reconnect once the server closes the connection
+        self.shutdown.send_replace(true);
+
+        // A timeout too long to represent, e.g. `Duration::MAX`, waits for as long as it takes
+        let deadline = Instant::now().checked_add(timeout);
+        let session = self.session.lock().await.clone();
+
+        let mut first_error = match until(deadline, self.leave(&session)).await {
+            Some(first_error) => first_error,
+            None => Some(CTraderError::TimeoutError {
+                task: "log out before shutting down".into(),
+                duration: timeout,
+            }),
+        };
+
+        let close = async { self.ws_write.lock().await.send(Message::Close(None)).await };
+        if let Some(Err(e)) = until(deadline, close).await {
+            tracing::warn!("Error while shutting down the CTrader client: {}", e);
+            first_error.get_or_insert(e.into());
+        }
+
+        self.join_until(deadline).await;
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Unsubscribe every stream and log out every account of `session`, returning the first error
+    async fn leave(&self, session: &Session) -> Option<CTraderError> {
+        let mut first_error = None;
+        let mut keep_first = |result: CTraderResult<()>| {
+            if let Err(e) = result {
+                tracing::warn!("Error while shutting down the CTrader client: {}", e);
+                first_error.get_or_insert(e);
+            }
+        };
+
+        // Live trendbars must go before the spot subscriptions they depend on
+        for (account_id, symbol_id, period) in &session.trendbars {
+            let result = match ProtoOaTrendbarPeriod::try_from(*period) {
+                Ok(period) => self
+                    .send_unsubscribe_live_trendbar_request(*account_id, *symbol_id, period)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(CTraderError::Other(e.to_string())),
+            };
+            keep_first(result);
+        }
+
+        let mut depth: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        for (account_id, symbol_id) in &session.depth {
+            depth.entry(*account_id).or_default().push(*symbol_id);
+        }
+        for (account_id, symbol_ids) in depth {
+            let result = self
+                .send_unsubscribe_depth_quotes_request(account_id, symbol_ids)
+                .await;
+            keep_first(result.map(|_| ()));
+        }
+
+        let mut spots: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        for (account_id, symbol_id) in session.spots.keys() {
+            spots.entry(*account_id).or_default().push(*symbol_id);
+        }
+        for (account_id, symbol_ids) in spots {
+            let result = self
+                .send_unsubscribe_spots_request(account_id, symbol_ids)
+                .await;
+            keep_first(result.map(|_| ()));
+        }
+
+        for account_id in &session.accounts {
+            let result = self.send_account_logout_request(*account_id).await;
+            keep_first(result.map(|_| ()));
+        }
+
+        first_error
+    }
+
+    /// Wait until `deadline` for the background tasks, then abort the ones still running.
+    /// Without a deadline wait for all of them to stop.
+    async fn join_until(&self, deadline: Option<Instant>) {
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap_or_else(|e| e.into_inner()));
+
+        for mut task in tasks {
+            if until(deadline, &mut task).await.is_none() {
+                tracing::warn!("CTrader client task did not stop before the shutdown deadline");
+                task.abort();
+            }
+        }
+    }
+}
+
+impl Drop for ClientGuard {
+    fn drop(&mut self) {
+        // Every handle is gone, nothing can call `shutdown` or `join` anymore
+        self.shutdown.send_replace(true);
+
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap_or_else(|e| e.into_inner()));
+        for task in tasks {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::codec;
+    use crate::client::mock::{connect, mock_server, next_request};
+    use crate::error::CTraderError;
+    use crate::openapi::{ProtoOaAccountLogoutReq, ProtoOaAccountLogoutRes};
+    use futures_util::SinkExt;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_shutdown_logs_out_and_closes() -> anyhow::Result<()> {
+        // Answer the logout request, then report whether the client closed the connection
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            let mut logged_out = Vec::new();
+
+            while let Some(frame) = next_request(&mut ws_stream).await? {
+                let req: ProtoOaAccountLogoutReq = frame.payload()?;
+                logged_out.push(req.ctid_trader_account_id);
+
+                let res = ProtoOaAccountLogoutRes {
+                    ctid_trader_account_id: req.ctid_trader_account_id,
+                    ..Default::default()
+                };
+                ws_stream
+                    .send(codec::encode(&res, frame.client_msg_id))
+                    .await?;
+            }
+
+            anyhow::Ok(logged_out)
+        })
+        .await?;
+
+        let client = connect(url).await?;
+
+        client.session.lock().await.accounts.insert(7);
+
+        client.shutdown(Duration::from_secs(5)).await?;
+
+        assert_eq!(server_handle.await??, vec![7]);
+        assert!(client.tasks.lock().unwrap().is_empty());
+        assert!(client.session.lock().await.accounts.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_is_bounded_by_its_timeout() -> anyhow::Result<()> {
+        // Read the logout request and never answer it
+        let (url, _server_handle) = mock_server(|mut ws_stream| async move {
+            next_request(&mut ws_stream).await?;
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            anyhow::Ok(ws_stream)
+        })
+        .await?;
+
+        let client = connect(url).await?;
+
+        client.session.lock().await.accounts.insert(7);
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(2),
+            client.shutdown(Duration::from_millis(200)),
+        )
+        .await?;
+
+        assert!(matches!(result, Err(CTraderError::TimeoutError { .. })));
+        assert!(client.tasks.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_without_deadline() -> anyhow::Result<()> {
+        let (url, server_handle) =
+            mock_server(|mut ws_stream| async move { next_request(&mut ws_stream).await }).await?;
+
+        let client = connect(url).await?;
+
+        client.shutdown(Duration::MAX).await?;
+
+        assert!(server_handle.await??.is_none());
+        assert!(client.tasks.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dropping_the_client_stops_it() -> anyhow::Result<()> {
+        // Wait for the connection to go away without a close frame
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            anyhow::Ok(next_request(&mut ws_stream).await.is_err())
+        })
+        .await?;
+
+        let client = connect(url).await?;
+
+        let shutdown = client.shutdown.subscribe();
+        let handle = client.clone();
+        drop(client);
+
+        // A remaining handle keeps the client running
+        assert!(!*shutdown.borrow());
+
+        drop(handle);
+
+        assert!(*shutdown.borrow());
+        assert!(tokio::time::timeout(Duration::from_secs(5), server_handle).await???);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use super::traits::TokenStore;
+use crate::error::CTraderResult;
+use crate::types::{Auth, FileTokenStore, MemoryTokenStore};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use tokio::io::AsyncWriteExt;
+
+/// The saved form of `Auth`, the only place its secrets are serialized
+#[derive(Serialize)]
+struct StoredAuth<'a> {
+    ctrader_access_token: &'a str,
+    ctrader_client_id: &'a str,
+    ctrader_client_secret: &'a str,
+    ctrader_redirect_url: &'a str,
+    ctrader_refresh_token: &'a str,
+    ctrader_token_expires_at: Option<SystemTime>,
+}
+
+impl<'a> From<&'a Auth> for StoredAuth<'a> {
+    fn from(auth: &'a Auth) -> Self {
+        Self {
+            ctrader_access_token: auth.ctrader_access_token.expose(),
+            ctrader_client_id: &auth.ctrader_client_id,
+            ctrader_client_secret: auth.ctrader_client_secret.expose(),
+            ctrader_redirect_url: &auth.ctrader_redirect_url,
+            ctrader_refresh_token: auth.ctrader_refresh_token.expose(),
+            ctrader_token_expires_at: auth.ctrader_token_expires_at,
+        }
+    }
+}
+
+impl Auth {
+    /// Serialize the credentials, secrets included, in the JSON format `FileTokenStore` saves
+    /// and `Auth` deserializes from. Keep the result somewhere only the current user can read.
+    pub fn to_stored_json(&self) -> CTraderResult<String> {
+        Ok(serde_json::to_string_pretty(&StoredAuth::from(self))?)
+    }
+}
+
+impl FileTokenStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl TokenStore for FileTokenStore {
+    async fn load(&self) -> CTraderResult<Option<Auth>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn save(&self, auth: &Auth) -> CTraderResult<()> {
+        let data = auth.to_stored_json()?;
+
+        // Write a sibling file and rename it over the old one so a crash never leaves half a file
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        // The mode only applies to a new file, so never reuse one left behind by a crash
+        match tokio::fs::remove_file(&tmp_path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let mut file = options.open(&tmp_path).await?;
+        file.write_all(data.as_bytes()).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+
+        // Make the rename itself durable, otherwise a crash may bring back the old tokens
+        #[cfg(unix)]
+        if let Some(parent) = self.path.parent() {
+            let parent = if parent.as_os_str().is_empty() {
+                std::path::Path::new(".")
+            } else {
+                parent
+            };
+            tokio::fs::File::open(parent).await?.sync_all().await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl MemoryTokenStore {
+    pub fn new(auth: Option<Auth>) -> Self {
+        Self {
+            auth: std::sync::Mutex::new(auth),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenStore for MemoryTokenStore {
+    async fn load(&self) -> CTraderResult<Option<Auth>> {
+        Ok(self.auth.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+
+    async fn save(&self, auth: &Auth) -> CTraderResult<()> {
+        *self.auth.lock().unwrap_or_else(|e| e.into_inner()) = Some(auth.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::codec;
+    use crate::client::mock::{builder, mock_server, next_request};
+    use crate::openapi::{ProtoOaRefreshTokenReq, ProtoOaRefreshTokenRes};
+    use futures_util::SinkExt;
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    fn auth(refresh_token: &str) -> Auth {
+        let mut auth = Auth::new(
+            "id".into(),
+            "access".into(),
+            "secret".into(),
+            "http://localhost".into(),
+            refresh_token.into(),
+        );
+        auth.ctrader_token_expires_at = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1));
+        auth
+    }
+
+    #[tokio::test]
+    async fn test_file_token_store() -> anyhow::Result<()> {
+        let path =
+            std::env::temp_dir().join(format!("ctrader-rs-tokens-{}.json", std::process::id()));
+        let store = FileTokenStore::new(&path);
+
+        assert!(store.load().await?.is_none());
+
+        // A temporary file left behind by a crash, readable by everyone
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, "stale")?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&tmp_path, std::fs::Permissions::from_mode(0o644))?;
+        }
+
+        store.save(&auth("first")).await?;
+        store.save(&auth("second")).await?;
+
+        let loaded = store.load().await?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path)?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        std::fs::remove_file(&path)?;
+
+        let loaded = loaded.expect("saved credentials");
+        assert_eq!(loaded.ctrader_refresh_token.expose(), "second");
+        assert_eq!(
+            loaded.ctrader_token_expires_at,
+            auth("second").ctrader_token_expires_at
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stored_json_round_trip() -> anyhow::Result<()> {
+        let json = auth("refresh").to_stored_json()?;
+        let loaded: Auth = serde_json::from_str(&json)?;
+
+        assert_eq!(loaded.ctrader_access_token.expose(), "access");
+        assert_eq!(loaded.ctrader_client_secret.expose(), "secret");
+        assert_eq!(loaded.ctrader_refresh_token.expose(), "refresh");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_memory_token_store() -> anyhow::Result<()> {
+        let store = MemoryTokenStore::default();
+
+        assert!(store.load().await?.is_none());
+
+        store.save(&auth("rotated")).await?;
+
+        assert_eq!(
+            store
+                .load()
+                .await?
+                .map(|auth| auth.ctrader_refresh_token.expose().to_string()),
+            Some("rotated".into())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_client_uses_and_updates_token_store() -> anyhow::Result<()> {
+        // Rotate the stored tokens on the first refresh request
+        let (url, server_handle) = mock_server(|mut ws_stream| async move {
+            let frame = next_request(&mut ws_stream)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("the client closed the connection"))?;
+            let req: ProtoOaRefreshTokenReq = frame.payload()?;
+
+            let res = ProtoOaRefreshTokenRes {
+                access_token: "new-access".into(),
+                refresh_token: "new-refresh".into(),
+                expires_in: 30 * 24 * 60 * 60,
+                token_type: "bearer".into(),
+                ..Default::default()
+            };
+            ws_stream
+                .send(codec::encode(&res, frame.client_msg_id))
+                .await?;
+
+            anyhow::Ok((req.refresh_token, ws_stream))
+        })
+        .await?;
+
+        let mut stored = Auth::new(
+            "id".into(),
+            "stored-access".into(),
+            "secret".into(),
+            "http://localhost".into(),
+            "stored-refresh".into(),
+        );
+        stored.ctrader_token_expires_at =
+            Some(SystemTime::now() + Duration::from_secs(30 * 24 * 60 * 60));
+        let store = Arc::new(MemoryTokenStore::new(Some(stored)));
+
+        // The builder is configured with the "access" and "refresh" tokens
+        let client = builder(url).token_store(store.clone()).connect().await?;
+
+        assert_eq!(
+            client.auth.lock().await.ctrader_access_token.expose(),
+            "stored-access"
+        );
+
+        client.refresh_tokens().await?;
+
+        let (refresh_token, _ws_stream) = server_handle.await??;
+        assert_eq!(refresh_token, "stored-refresh");
+
+        let saved = store.load().await?.expect("saved credentials");
+        assert_eq!(saved.ctrader_access_token.expose(), "new-access");
+        assert_eq!(saved.ctrader_refresh_token.expose(), "new-refresh");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,757 @@
+use crate::error::CTraderResult;
+use crate::openapi::{
+    ProtoOaAssetClassListRes, ProtoOaAssetListRes, ProtoOaDealOffsetListRes, ProtoOaExecutionEvent,
+    ProtoOaGetTickDataRes, ProtoOaGetTrendbarsRes, ProtoOaOrderDetailsRes,
+    ProtoOaOrderListByPositionIdRes, ProtoOaOrderType, ProtoOaQuoteType, ProtoOaReconcileRes,
+    ProtoOaSubscribeDepthQuotesRes, ProtoOaSubscribeLiveTrendbarRes, ProtoOaSubscribeSpotsRes,
+    ProtoOaSymbolCategoryListRes, ProtoOaSymbolsListRes, ProtoOaTradeSide, ProtoOaTrendbarPeriod,
+    ProtoOaUnsubscribeDepthQuotesRes, ProtoOaUnsubscribeLiveTrendbarRes,
+    ProtoOaUnsubscribeSpotsRes,
+};
+use crate::openapi::{
+    ProtoOaCashFlowHistoryListRes, ProtoOaDealListByPositionIdRes, ProtoOaDealListRes,
+    ProtoOaExpectedMarginRes, ProtoOaGetDynamicLeverageByIdRes, ProtoOaMarginCall,
+    ProtoOaMarginCallListRes, ProtoOaMarginCallUpdateRes, ProtoOaOrderListRes,
+    ProtoOaSymbolByIdRes, ProtoOaSymbolsForConversionRes, ProtoOaVersionRes,
+};
+use crate::types::{AmendOrder, Auth, CTraderClient, OrderRequest};
+use async_trait::async_trait;
+
+/// A message sent in a `ProtoMessage` envelope
+pub trait Payload: prost::Message + Default {
+    /// The id of the message in `ProtoOaPayloadType`, or `ProtoPayloadType` for the common messages
+    const PAYLOAD_TYPE: u32;
+}
+
+/// Persists the credentials so tokens rotated by the client survive a restart
+#[async_trait]
+pub trait TokenStore: Send + Sync + std::fmt::Debug {
+    /// Load the last saved credentials, `None` when nothing was saved yet
+    async fn load(&self) -> CTraderResult<Option<Auth>>;
+
+    /// Save the credentials, replacing the ones saved before
+    async fn save(&self, auth: &Auth) -> CTraderResult<()>;
+}
+
+/// Connection and session state that has to survive a reconnect
+pub trait AppState: Send + Sync {
+    /// Trading accounts authorized on the connection
+    fn authorized_accounts(&self) -> Vec<i64>;
+
+    /// Spot subscriptions as (account, symbol)
+    fn spot_subscriptions(&self) -> Vec<(i64, i64)>;
+
+    /// Live trendbar subscriptions as (account, symbol, period)
+    fn trendbar_subscriptions(&self) -> Vec<(i64, i64, i32)>;
+
+    /// Depth of market subscriptions as (account, symbol)
+    fn depth_subscriptions(&self) -> Vec<(i64, i64)>;
+
+    /// Whether the account has been authorized on the connection
+    fn is_account_authorized(&self, account_id: i64) -> bool {
+        self.authorized_accounts().contains(&account_id)
+    }
+
+    /// Whether there is nothing to restore after a reconnect
+    fn is_empty(&self) -> bool {
+        self.authorized_accounts().is_empty()
+            && self.spot_subscriptions().is_empty()
+            && self.trendbar_subscriptions().is_empty()
+            && self.depth_subscriptions().is_empty()
+    }
+}
+
+/// Placing, amending and inspecting orders and positions
+#[async_trait]
+pub trait TradingApi: Send + Sync {
+    async fn send_new_order(
+        &self,
+        account_id: i64,
+        order: &OrderRequest,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_new_market_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_new_limit_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: f64,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_new_stop_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: f64,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_new_market_range_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        base_slippage_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_new_stop_limit_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        stop_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_new_order_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        order_type: ProtoOaOrderType,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: Option<f64>,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_cancel_order_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_close_position_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_reconcile_request(&self, account_id: i64) -> CTraderResult<ProtoOaReconcileRes>;
+
+    async fn send_order_details_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaOrderDetailsRes>;
+
+    async fn send_order_list_by_position_id_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListByPositionIdRes>;
+
+    async fn send_deal_offset_list_request(
+        &self,
+        account_id: i64,
+        deal_id: i64,
+    ) -> CTraderResult<ProtoOaDealOffsetListRes>;
+
+    async fn send_amend_order_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+        amend: &AmendOrder,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_amend_position_sltp_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        stop_loss: Option<f64>,
+        take_profit: Option<f64>,
+        trailing_stop_loss: bool,
+    ) -> CTraderResult<ProtoOaExecutionEvent>;
+
+    async fn send_deal_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+        max_rows: Option<i32>,
+    ) -> CTraderResult<ProtoOaDealListRes>;
+
+    async fn send_deal_list_by_position_id_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaDealListByPositionIdRes>;
+
+    async fn send_order_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListRes>;
+
+    async fn send_expected_margin_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        volume: Vec<i64>,
+    ) -> CTraderResult<ProtoOaExpectedMarginRes>;
+
+    async fn send_cash_flow_history_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaCashFlowHistoryListRes>;
+
+    async fn send_margin_call_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaMarginCallListRes>;
+
+    async fn send_margin_call_update_request(
+        &self,
+        account_id: i64,
+        margin_call: ProtoOaMarginCall,
+    ) -> CTraderResult<ProtoOaMarginCallUpdateRes>;
+}
+
+/// Symbols, assets, price history and live market data subscriptions
+#[async_trait]
+pub trait MarketDataApi: Send + Sync {
+    async fn send_asset_list_request(&self, account_id: i64) -> CTraderResult<ProtoOaAssetListRes>;
+
+    async fn send_asset_class_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaAssetClassListRes>;
+
+    async fn send_symbol_category_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaSymbolCategoryListRes>;
+
+    async fn send_symbols_list_request(
+        &self,
+        account_id: i64,
+        include_archived_symbols: bool,
+    ) -> CTraderResult<ProtoOaSymbolsListRes>;
+
+    async fn send_subscribe_spots_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+        subscribe_to_spot_timestamp: bool,
+    ) -> CTraderResult<ProtoOaSubscribeSpotsRes>;
+
+    async fn send_unsubscribe_spots_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeSpotsRes>;
+
+    async fn send_subscribe_live_trendbar_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaSubscribeLiveTrendbarRes>;
+
+    async fn send_unsubscribe_live_trendbar_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaUnsubscribeLiveTrendbarRes>;
+
+    async fn send_subscribe_depth_quotes_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSubscribeDepthQuotesRes>;
+
+    async fn send_unsubscribe_depth_quotes_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeDepthQuotesRes>;
+
+    async fn send_get_trendbars_request(
+        &self,
+        account_id: i64,
+        period: i32,
+        symbol_id: i64,
+        count: u32,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaGetTrendbarsRes>;
+
+    async fn send_get_tick_data_request(
+        &self,
+        account_id: i64,
+        quote_type: ProtoOaQuoteType,
+        symbol_id: i64,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> CTraderResult<ProtoOaGetTickDataRes>;
+
+    async fn send_symbol_by_id_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSymbolByIdRes>;
+
+    async fn send_symbols_for_conversion_request(
+        &self,
+        account_id: i64,
+        first_asset_id: i64,
+        last_asset_id: i64,
+    ) -> CTraderResult<ProtoOaSymbolsForConversionRes>;
+
+    async fn send_get_dynamic_leverage_by_id_request(
+        &self,
+        account_id: i64,
+        leverage_id: i64,
+    ) -> CTraderResult<ProtoOaGetDynamicLeverageByIdRes>;
+
+    async fn send_version_request(&self) -> CTraderResult<ProtoOaVersionRes>;
+}
+
+#[async_trait]
+impl TradingApi for CTraderClient {
+    async fn send_new_order(
+        &self,
+        account_id: i64,
+        order: &OrderRequest,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_order(self, account_id, order).await
+    }
+
+    async fn send_new_market_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_market_order(self, account_id, symbol_id, trade_side, volume).await
+    }
+
+    async fn send_new_limit_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: f64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_limit_order(self, account_id, symbol_id, trade_side, volume, price)
+            .await
+    }
+
+    async fn send_new_stop_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: f64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_stop_order(self, account_id, symbol_id, trade_side, volume, price)
+            .await
+    }
+
+    async fn send_new_market_range_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        base_slippage_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_market_range_order(
+            self,
+            account_id,
+            symbol_id,
+            trade_side,
+            volume,
+            base_slippage_price,
+            slippage_in_points,
+        )
+        .await
+    }
+
+    async fn send_new_stop_limit_order(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        stop_price: f64,
+        slippage_in_points: i32,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_stop_limit_order(
+            self,
+            account_id,
+            symbol_id,
+            trade_side,
+            volume,
+            stop_price,
+            slippage_in_points,
+        )
+        .await
+    }
+
+    async fn send_new_order_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        order_type: ProtoOaOrderType,
+        trade_side: ProtoOaTradeSide,
+        volume: i64,
+        price: Option<f64>,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_new_order_request(
+            self, account_id, symbol_id, order_type, trade_side, volume, price,
+        )
+        .await
+    }
+
+    async fn send_cancel_order_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_cancel_order_request(self, account_id, order_id).await
+    }
+
+    async fn send_close_position_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        volume: i64,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_close_position_request(self, account_id, position_id, volume).await
+    }
+
+    async fn send_reconcile_request(&self, account_id: i64) -> CTraderResult<ProtoOaReconcileRes> {
+        CTraderClient::send_reconcile_request(self, account_id).await
+    }
+
+    async fn send_order_details_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+    ) -> CTraderResult<ProtoOaOrderDetailsRes> {
+        CTraderClient::send_order_details_request(self, account_id, order_id).await
+    }
+
+    async fn send_order_list_by_position_id_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListByPositionIdRes> {
+        CTraderClient::send_order_list_by_position_id_request(
+            self,
+            account_id,
+            position_id,
+            from_timestamp,
+            to_timestamp,
+        )
+        .await
+    }
+
+    async fn send_deal_offset_list_request(
+        &self,
+        account_id: i64,
+        deal_id: i64,
+    ) -> CTraderResult<ProtoOaDealOffsetListRes> {
+        CTraderClient::send_deal_offset_list_request(self, account_id, deal_id).await
+    }
+
+    async fn send_amend_order_request(
+        &self,
+        account_id: i64,
+        order_id: i64,
+        amend: &AmendOrder,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_amend_order_request(self, account_id, order_id, amend).await
+    }
+
+    async fn send_amend_position_sltp_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        stop_loss: Option<f64>,
+        take_profit: Option<f64>,
+        trailing_stop_loss: bool,
+    ) -> CTraderResult<ProtoOaExecutionEvent> {
+        CTraderClient::send_amend_position_sltp_request(
+            self,
+            account_id,
+            position_id,
+            stop_loss,
+            take_profit,
+            trailing_stop_loss,
+        )
+        .await
+    }
+
+    async fn send_deal_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+        max_rows: Option<i32>,
+    ) -> CTraderResult<ProtoOaDealListRes> {
+        CTraderClient::send_deal_list_request(
+            self,
+            account_id,
+            from_timestamp,
+            to_timestamp,
+            max_rows,
+        )
+        .await
+    }
+
+    async fn send_deal_list_by_position_id_request(
+        &self,
+        account_id: i64,
+        position_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaDealListByPositionIdRes> {
+        CTraderClient::send_deal_list_by_position_id_request(
+            self,
+            account_id,
+            position_id,
+            from_timestamp,
+            to_timestamp,
+        )
+        .await
+    }
+
+    async fn send_order_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaOrderListRes> {
+        CTraderClient::send_order_list_request(self, account_id, from_timestamp, to_timestamp).await
+    }
+
+    async fn send_expected_margin_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        volume: Vec<i64>,
+    ) -> CTraderResult<ProtoOaExpectedMarginRes> {
+        CTraderClient::send_expected_margin_request(self, account_id, symbol_id, volume).await
+    }
+
+    async fn send_cash_flow_history_list_request(
+        &self,
+        account_id: i64,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaCashFlowHistoryListRes> {
+        CTraderClient::send_cash_flow_history_list_request(
+            self,
+            account_id,
+            from_timestamp,
+            to_timestamp,
+        )
+        .await
+    }
+
+    async fn send_margin_call_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaMarginCallListRes> {
+        CTraderClient::send_margin_call_list_request(self, account_id).await
+    }
+
+    async fn send_margin_call_update_request(
+        &self,
+        account_id: i64,
+        margin_call: ProtoOaMarginCall,
+    ) -> CTraderResult<ProtoOaMarginCallUpdateRes> {
+        CTraderClient::send_margin_call_update_request(self, account_id, margin_call).await
+    }
+}
+
+#[async_trait]
+impl MarketDataApi for CTraderClient {
+    async fn send_asset_list_request(&self, account_id: i64) -> CTraderResult<ProtoOaAssetListRes> {
+        CTraderClient::send_asset_list_request(self, account_id).await
+    }
+
+    async fn send_asset_class_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaAssetClassListRes> {
+        CTraderClient::send_asset_class_list_request(self, account_id).await
+    }
+
+    async fn send_symbol_category_list_request(
+        &self,
+        account_id: i64,
+    ) -> CTraderResult<ProtoOaSymbolCategoryListRes> {
+        CTraderClient::send_symbol_category_list_request(self, account_id).await
+    }
+
+    async fn send_symbols_list_request(
+        &self,
+        account_id: i64,
+        include_archived_symbols: bool,
+    ) -> CTraderResult<ProtoOaSymbolsListRes> {
+        CTraderClient::send_symbols_list_request(self, account_id, include_archived_symbols).await
+    }
+
+    async fn send_subscribe_spots_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+        subscribe_to_spot_timestamp: bool,
+    ) -> CTraderResult<ProtoOaSubscribeSpotsRes> {
+        CTraderClient::send_subscribe_spots_request(
+            self,
+            account_id,
+            symbol_id,
+            subscribe_to_spot_timestamp,
+        )
+        .await
+    }
+
+    async fn send_unsubscribe_spots_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeSpotsRes> {
+        CTraderClient::send_unsubscribe_spots_request(self, account_id, symbol_id).await
+    }
+
+    async fn send_subscribe_live_trendbar_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaSubscribeLiveTrendbarRes> {
+        CTraderClient::send_subscribe_live_trendbar_request(self, account_id, symbol_id, period)
+            .await
+    }
+
+    async fn send_unsubscribe_live_trendbar_request(
+        &self,
+        account_id: i64,
+        symbol_id: i64,
+        period: ProtoOaTrendbarPeriod,
+    ) -> CTraderResult<ProtoOaUnsubscribeLiveTrendbarRes> {
+        CTraderClient::send_unsubscribe_live_trendbar_request(self, account_id, symbol_id, period)
+            .await
+    }
+
+    async fn send_subscribe_depth_quotes_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSubscribeDepthQuotesRes> {
+        CTraderClient::send_subscribe_depth_quotes_request(self, account_id, symbol_id).await
+    }
+
+    async fn send_unsubscribe_depth_quotes_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaUnsubscribeDepthQuotesRes> {
+        CTraderClient::send_unsubscribe_depth_quotes_request(self, account_id, symbol_id).await
+    }
+
+    async fn send_get_trendbars_request(
+        &self,
+        account_id: i64,
+        period: i32,
+        symbol_id: i64,
+        count: u32,
+        from_timestamp: i64,
+        to_timestamp: i64,
+    ) -> CTraderResult<ProtoOaGetTrendbarsRes> {
+        CTraderClient::send_get_trendbars_request(
+            self,
+            account_id,
+            period,
+            symbol_id,
+            count,
+            from_timestamp,
+            to_timestamp,
+        )
+        .await
+    }
+
+    async fn send_get_tick_data_request(
+        &self,
+        account_id: i64,
+        quote_type: ProtoOaQuoteType,
+        symbol_id: i64,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> CTraderResult<ProtoOaGetTickDataRes> {
+        CTraderClient::send_get_tick_data_request(
+            self,
+            account_id,
+            quote_type,
+            symbol_id,
+            from_timestamp,
+            to_timestamp,
+        )
+        .await
+    }
+
+    async fn send_symbol_by_id_request(
+        &self,
+        account_id: i64,
+        symbol_id: Vec<i64>,
+    ) -> CTraderResult<ProtoOaSymbolByIdRes> {
+        CTraderClient::send_symbol_by_id_request(self, account_id, symbol_id).await
+    }
+
+    async fn send_symbols_for_conversion_request(
+        &self,
+        account_id: i64,
+        first_asset_id: i64,
+        last_asset_id: i64,
+    ) -> CTraderResult<ProtoOaSymbolsForConversionRes> {
+        CTraderClient::send_symbols_for_conversion_request(
+            self,
+            account_id,
+            first_asset_id,
+            last_asset_id,
+        )
+        .await
+    }
+
+    async fn send_get_dynamic_leverage_by_id_request(
+        &self,
+        account_id: i64,
+        leverage_id: i64,
+    ) -> CTraderResult<ProtoOaGetDynamicLeverageByIdRes> {
+        CTraderClient::send_get_dynamic_leverage_by_id_request(self, account_id, leverage_id).await
+    }
+
+    async fn send_version_request(&self) -> CTraderResult<ProtoOaVersionRes> {
+        CTraderClient::send_version_request(self).await
+    }
+}
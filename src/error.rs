@@ -1,3 +1,7 @@
+use crate::openapi::{
+    ProtoErrorCode, ProtoErrorRes, ProtoOaErrorCode, ProtoOaErrorRes, ProtoOaOrderErrorEvent,
+};
+use std::fmt;
 use std::time::Duration;
 
 #[derive(thiserror::Error, Debug)]
@@ -9,8 +13,59 @@ pub enum CTraderError {
     #[error("Module '{0}' not found.")]
     ModuleNotFound(String),
 
-    #[error("HTTP request error: {0}")]
-    HttpRequest(String),
+    /// An HTTP request to the CTrader OAuth endpoints failed.
+    /// * status - The HTTP status of the response, `None` when no response was received
+    /// * message - What went wrong
+    /// * body - The start of the response body
+    /// * error - The OAuth error code sent by the server
+    /// * error_description - The OAuth error description sent by the server
+    #[error(
+        "HTTP request error{}: {message}{}",
+        status.map(|status| format!(" ({})", status)).unwrap_or_default(),
+        error
+            .as_ref()
+            .map(|error| format!(" ({}: {})", error, error_description.as_deref().unwrap_or_default()))
+            .unwrap_or_default()
+    )]
+    HttpRequest {
+        status: Option<u16>,
+        message: String,
+        body: Option<String>,
+        error: Option<String>,
+        error_description: Option<String>,
+    },
+
+    /// Error returned by the CTrader OpenAPI in response to a request.
+    /// * code - The error code sent by the server
+    /// * description - The human readable description sent along with the code
+    /// * maintenance_end - When the server is under maintenance, the timestamp in ms it ends at
+    #[error("Open API error {code}: {description}")]
+    Api {
+        code: ErrorCode,
+        description: String,
+        maintenance_end: Option<i64>,
+    },
+
+    /// An order was not sent because its fields do not fit its order type
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+
+    #[error("Connector error: {0}")]
+    Connector(#[from] ConnectorError),
+
+    /// The connection was lost before the response to the request with this `client_msg_id`
+    /// arrived
+    #[error("Connection closed before a response to request {0} arrived")]
+    Disconnected(String),
+
+    /// Parts of the session could not be restored after a reconnect, the rest was restored.
+    /// * errors - Why each account or subscription could not be restored
+    #[error(
+        "Unable to restore {} parts of the session, first error: {}",
+        errors.len(),
+        errors.first().map(ToString::to_string).unwrap_or_default()
+    )]
+    SessionRestore { errors: Vec<CTraderError> },
 
     #[error("Other error: {0}")]
     Other(String),
@@ -18,6 +73,9 @@ pub enum CTraderError {
     #[error("Serialization error: {0}")]
     Serde(#[from] serde_json::Error),
 
+    #[error("Protobuf decode error: {0}")]
+    Decode(#[from] prost::DecodeError),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -28,8 +86,253 @@ pub enum CTraderError {
     TimeoutError { task: String, duration: Duration },
 }
 
+impl From<tokio_tungstenite::tungstenite::Error> for CTraderError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocket(Box::new(err))
+    }
+}
+
+impl CTraderError {
+    /// Whether the server rejected the request without executing it, so sending the same
+    /// request again is safe even for orders. See `is_transient` for failures where the
+    /// outcome of the request is unknown.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Api {
+                code: ErrorCode::Common(
+                    ProtoErrorCode::CantRouteRequest | ProtoErrorCode::ConcurrentModification
+                ) | ErrorCode::OpenApi(
+                    ProtoOaErrorCode::ChServerNotReachable
+                        | ProtoOaErrorCode::RequestFrequencyExceeded
+                        | ProtoOaErrorCode::ServerIsUnderMaintenance
+                        | ProtoOaErrorCode::NoQuotes
+                        | ProtoOaErrorCode::PositionLocked
+                        | ProtoOaErrorCode::PendingExecution
+                ),
+                ..
+            }
+        )
+    }
+
+    /// Whether the failure may go away by itself, e.g. a dropped connection or a request that
+    /// timed out. Unlike `is_retryable` the request may still have reached the server and been
+    /// executed, so read only requests can be sent again but trading requests that are not
+    /// idempotent (new orders, amendments, closing positions) must be reconciled first, e.g.
+    /// with `send_reconcile_request` or by looking up their `client_order_id`.
+    pub fn is_transient(&self) -> bool {
+        self.is_retryable()
+            || matches!(
+                self,
+                Self::WebSocket(_)
+                    | Self::Connector(ConnectorError::RetriesExhausted { .. })
+                    | Self::Disconnected(_)
+                    | Self::Io(_)
+                    | Self::TimeoutError { .. }
+                    | Self::Api {
+                        code: ErrorCode::Common(ProtoErrorCode::TimeoutError),
+                        ..
+                    }
+            )
+    }
+
+    /// Whether the application or account credentials were rejected or have expired
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            Self::Api {
+                code: ErrorCode::OpenApi(
+                    ProtoOaErrorCode::OaAuthTokenExpired
+                        | ProtoOaErrorCode::AccountNotAuthorized
+                        | ProtoOaErrorCode::RetNoSuchLogin
+                        | ProtoOaErrorCode::RetAccountDisabled
+                        | ProtoOaErrorCode::ChClientAuthFailure
+                        | ProtoOaErrorCode::ChClientNotAuthenticated
+                        | ProtoOaErrorCode::ChAccessTokenInvalid
+                        | ProtoOaErrorCode::ChOaClientNotFound
+                ),
+                ..
+            }
+        )
+    }
+
+    /// Whether the server rejected the request because it is under maintenance
+    pub fn is_maintenance(&self) -> bool {
+        matches!(
+            self,
+            Self::Api {
+                code: ErrorCode::OpenApi(ProtoOaErrorCode::ServerIsUnderMaintenance),
+                ..
+            } | Self::Api {
+                maintenance_end: Some(_),
+                ..
+            }
+        )
+    }
+}
+
+impl From<ProtoOaErrorRes> for CTraderError {
+    fn from(res: ProtoOaErrorRes) -> Self {
+        Self::Api {
+            code: ErrorCode::from(res.error_code.as_str()),
+            description: res.description.unwrap_or_default(),
+            maintenance_end: res.maintenance_end_timestamp,
+        }
+    }
+}
+
+impl From<ProtoErrorRes> for CTraderError {
+    fn from(res: ProtoErrorRes) -> Self {
+        Self::Api {
+            code: ErrorCode::from(res.error_code.as_str()),
+            description: res.description.unwrap_or_default(),
+            maintenance_end: res.maintenance_end_timestamp.map(|ts| ts as i64),
+        }
+    }
+}
+
+impl From<ProtoOaOrderErrorEvent> for CTraderError {
+    fn from(event: ProtoOaOrderErrorEvent) -> Self {
+        Self::Api {
+            code: ErrorCode::from(event.error_code.as_str()),
+            description: event.description.unwrap_or_default(),
+            maintenance_end: None,
+        }
+    }
+}
+
+/// The code of an error returned by the CTrader OpenAPI.
+/// * Common - A code shared by every CTrader API (`ProtoErrorCode`)
+/// * OpenApi - A code specific to the Open API (`ProtoOaErrorCode`)
+/// * Unknown - A code missing from the proto definitions this crate was built with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorCode {
+    Common(ProtoErrorCode),
+    OpenApi(ProtoOaErrorCode),
+    Unknown(String),
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        if let Some(code) = ProtoOaErrorCode::from_str_name(code) {
+            Self::OpenApi(code)
+        } else if let Some(code) = ProtoErrorCode::from_str_name(code) {
+            Self::Common(code)
+        } else {
+            Self::Unknown(code.to_string())
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Common(code) => f.write_str(code.as_str_name()),
+            Self::OpenApi(code) => f.write_str(code.as_str_name()),
+            Self::Unknown(code) => f.write_str(code),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
-pub enum ConnectorError {}
+pub enum ConnectorError {
+    #[error("Failed to connect to '{url}' after {attempts} attempts: {source}")]
+    RetriesExhausted {
+        url: String,
+        attempts: u32,
+        source: Box<tokio_tungstenite::tungstenite::Error>,
+    },
+
+    /// The client shut down before a connection could be established
+    #[error("Stopped connecting to '{url}' because the client shut down")]
+    Stopped { url: String },
+}
+
+/// A filtered event stream fell behind and `skipped` events were dropped, some of which may
+/// have been its own, e.g. fills of an `executions` stream. Reconcile to catch up.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Event stream lagged behind by {skipped} events")]
+pub struct Lagged {
+    pub skipped: u64,
+}
 
 pub type CTraderResult<T> = std::result::Result<T, CTraderError>;
 pub type ConnectorResult<T> = std::result::Result<T, ConnectorError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_res_is_classified() {
+        let err = CTraderError::from(ProtoOaErrorRes {
+            error_code: "NOT_ENOUGH_MONEY".into(),
+            description: Some("Not enough money".into()),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            err,
+            CTraderError::Api {
+                code: ErrorCode::OpenApi(ProtoOaErrorCode::NotEnoughMoney),
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Open API error NOT_ENOUGH_MONEY: Not enough money"
+        );
+        assert!(!err.is_retryable());
+        assert!(!err.is_transient());
+        assert!(!err.is_auth_error());
+
+        let err = CTraderError::from(ProtoOaErrorRes {
+            error_code: "CH_ACCESS_TOKEN_INVALID".into(),
+            ..Default::default()
+        });
+
+        assert!(err.is_auth_error());
+
+        let err = CTraderError::from(ProtoErrorRes {
+            error_code: "SERVER_IS_UNDER_MAINTENANCE".into(),
+            maintenance_end_timestamp: Some(1_700_000_000_000),
+            ..Default::default()
+        });
+
+        assert!(err.is_maintenance());
+        assert!(err.is_retryable());
+        assert!(err.is_transient());
+    }
+
+    #[test]
+    fn test_timeout_is_transient_but_not_retryable() {
+        // A timed out order may have been executed, it must not be sent blindly again
+        let err = CTraderError::TimeoutError {
+            task: "request 1 (payload type 2106)".into(),
+            duration: Duration::from_secs(5),
+        };
+
+        assert!(err.is_transient());
+        assert!(!err.is_retryable());
+
+        let err = CTraderError::from(ProtoErrorRes {
+            error_code: "TIMEOUT_ERROR".into(),
+            ..Default::default()
+        });
+
+        assert!(err.is_transient());
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_unknown_error_code_is_kept() {
+        let code = ErrorCode::from("SOMETHING_NEW");
+
+        assert_eq!(code, ErrorCode::Unknown("SOMETHING_NEW".into()));
+        assert_eq!(code.to_string(), "SOMETHING_NEW");
+        assert_eq!(
+            ErrorCode::from("TIMEOUT_ERROR"),
+            ErrorCode::Common(ProtoErrorCode::TimeoutError)
+        );
+    }
+}
@@ -2,10 +2,31 @@ mod client;
 mod error;
 mod types;
 
-pub mod openapi;
+/// Types generated from the CTrader OpenAPI `protos/`
+pub mod openapi {
+    include!(concat!(env!("OUT_DIR"), "/openapi.rs"));
+}
 
 pub mod prelude {
     pub use super::client::traits::*;
+    pub use super::error::*;
     pub use super::openapi::{self};
     pub use super::types::*;
 }
+
+#[cfg(all(test, feature = "regenerate-protos"))]
+mod tests {
+    /// Update the committed `generated/openapi.rs` with the module the build generated from
+    /// `protos/`, run it with
+    /// `cargo test --features regenerate-protos -- --ignored test_regenerate_openapi`
+    #[test]
+    #[ignore = "overwrites generated/openapi.rs"]
+    fn test_regenerate_openapi() -> anyhow::Result<()> {
+        let generated =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("generated/openapi.rs");
+
+        std::fs::copy(concat!(env!("OUT_DIR"), "/openapi.rs"), generated)?;
+
+        Ok(())
+    }
+}
@@ -1,27 +1,71 @@
+use crate::client::codec::Frame;
+use crate::client::traits::TokenStore;
+use crate::openapi::{
+    ProtoErrorRes, ProtoOaAccountDisconnectEvent, ProtoOaAccountsTokenInvalidatedEvent,
+    ProtoOaClientDisconnectEvent, ProtoOaDepthEvent, ProtoOaErrorRes, ProtoOaExecutionEvent,
+    ProtoOaMarginCallTriggerEvent, ProtoOaMarginCallUpdateEvent, ProtoOaMarginChangedEvent,
+    ProtoOaOrderErrorEvent, ProtoOaSpotEvent, ProtoOaSymbolChangedEvent, ProtoOaTraderUpdatedEvent,
+    ProtoOaTrailingSlChangedEvent,
+};
+use crate::openapi::{
+    ProtoOaOrderTriggerMethod, ProtoOaOrderType, ProtoOaTimeInForce, ProtoOaTradeSide,
+};
 use futures_util::stream::SplitSink;
-use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, atomic::AtomicU64};
+use std::time::{Duration, SystemTime};
+use tokio::sync::{Mutex, broadcast, oneshot, watch};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
 use tokio_tungstenite::{WebSocketStream, tungstenite::Message};
 
+/// A credential that never shows up in logs.
+/// Debug and Display print `[REDACTED]`, the value is wiped from memory on drop and
+/// it is not serializable, use `expose` where the value itself is needed.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(pub(crate) String);
+
 /// CTrader Auth details
+/// * ctrader_access_token - Ctrader Account Access Token
 /// * ctrader_client_id - CTrader Application Client ID
 /// * ctrader_client_secret - CTrader Application Secret
 /// * ctrader_redirect_url - Ctrader Application Redirect URL
 /// * ctrader_refresh_token - Ctrader Application Refresh Token
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// * ctrader_token_expires_at - When the access token expires, if known
+///
+/// Breaking change: `Auth` no longer implements `Serialize`, so its secrets can't be written
+/// out by accident. Use `Auth::to_stored_json` or a `TokenStore` to persist it, both produce
+/// the JSON `Auth` deserializes from.
+#[derive(Debug, Deserialize, Clone)]
 pub struct Auth {
-    pub ctrader_access_token: String,
+    pub ctrader_access_token: Secret,
     pub ctrader_client_id: String,
-    pub ctrader_client_secret: String,
+    pub ctrader_client_secret: Secret,
     pub ctrader_redirect_url: String,
-    pub ctrader_refresh_token: String,
+    pub ctrader_refresh_token: Secret,
+    #[serde(default)]
+    pub ctrader_token_expires_at: Option<SystemTime>,
 }
 
 /// The CTrader client instance
-/// * auth - The Authorization information to use.
+/// * auth - The Authorization information to use, swapped in place when the tokens are refreshed.
 /// * write_stream - The websocket stream to use to send to the websocket server.
 /// * read_stream - The websocket stream to use to receive messages from the websocket server.
+/// * pending - Requests awaiting a response, keyed by their `client_msg_id`.
+/// * events - Publishes every event decoded by the reader task.
+/// * last_sent - When a frame was last written, used to send heartbeats only when idle.
+/// * session - The accounts and subscriptions restored after a reconnect.
+/// * request_timeout - How long to wait for the response to a request.
+/// * transport - The wire format spoken on the websocket.
+/// * environment - The cTrader environment the client is connected to.
+/// * token_store - Where rotated tokens are saved, if anywhere.
+/// * tasks - The reader, heartbeat, token refresh and session restore tasks, awaited by `join`.
+/// * shutdown - Holds `true` once `shutdown` was called, stopping the background tasks.
+/// * guard - Stops the background tasks once the last handle is dropped, `None` in the copies
+///   held by the background tasks themselves so they don't keep the client alive.
 //
 //
 //
@@ -30,7 +74,7 @@ pub struct Auth {
 //
 #[derive(Debug, Clone)]
 pub struct CTraderClient {
-    pub auth: Auth,
+    pub auth: Arc<Mutex<Auth>>,
 
     pub ws_write: Arc<
         Mutex<
@@ -40,8 +84,314 @@ pub struct CTraderClient {
             >,
         >,
     >,
+
+    pub(crate) pending: PendingRequests,
+
+    pub(crate) next_msg_id: Arc<AtomicU64>,
+
+    pub(crate) events: broadcast::Sender<Event>,
+
+    pub(crate) last_sent: Arc<Mutex<Instant>>,
+
+    pub(crate) session: Arc<Mutex<Session>>,
+
+    pub request_timeout: Duration,
+
+    pub transport: Transport,
+
+    pub environment: Environment,
+
+    pub(crate) token_store: Option<Arc<dyn TokenStore>>,
+
+    pub(crate) tasks: Arc<std::sync::Mutex<Vec<JoinHandle<()>>>>,
+
+    pub(crate) shutdown: Arc<watch::Sender<bool>>,
+
+    #[allow(dead_code)]
+    pub(crate) guard: Option<Arc<ClientGuard>>,
+}
+
+/// Shared by the handles of a client given to the user, stops the client when dropped.
+/// * shutdown - Flipped to `true` so the background tasks stop on their own
+/// * tasks - Aborted in case they don't
+#[derive(Debug)]
+pub(crate) struct ClientGuard {
+    pub(crate) shutdown: Arc<watch::Sender<bool>>,
+    pub(crate) tasks: Arc<std::sync::Mutex<Vec<JoinHandle<()>>>>,
+}
+
+/// A trading account authorized on the connection of a client, see `CTraderClient::account`.
+/// Requests made through it are sent for that account and its streams only carry its events.
+/// * client - The client the account is authorized on
+/// * account_id - The `ctid_trader_account_id` of the account
+#[derive(Debug, Clone)]
+pub struct AccountSession {
+    pub(crate) client: CTraderClient,
+    pub(crate) account_id: i64,
+}
+
+/// A trading account the access token grants access to, see `CTraderClient::accounts`.
+/// * account_id - The `ctid_trader_account_id` of the account
+/// * is_live - Whether the account belongs to the live environment rather than the demo one
+/// * broker_title - Short name of the broker holding the account
+/// * trader_login - The login displayed for the account in the cTrader apps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraderAccount {
+    pub account_id: i64,
+    pub is_live: bool,
+    pub broker_title: Option<String>,
+    pub trader_login: Option<i64>,
+}
+
+/// A new order, covering every field of `ProtoOaNewOrderReq`.
+/// Start from `OrderRequest::market`, `limit`, `stop` or `new`, chain the optional settings and
+/// send it with `CTraderClient::send_new_order`, which validates it against its order type first.
+/// * symbol_id - The symbol to trade
+/// * order_type - MARKET, LIMIT, STOP, MARKET_RANGE or STOP_LIMIT
+/// * trade_side - BUY or SELL
+/// * volume - The volume in 0.01 of a unit, e.g. 1000 means 10.00 units
+/// * limit_price - The price of a LIMIT order
+/// * stop_price - The trigger price of a STOP or STOP_LIMIT order
+/// * time_in_force - When the order expires, GOOD_TILL_CANCEL when not set
+/// * expiration_timestamp - Unix time in ms a GOOD_TILL_DATE order expires at
+/// * stop_loss - Absolute stop loss price, not supported by market orders
+/// * take_profit - Absolute take profit price, not supported by market orders
+/// * comment - Free text of up to 512 characters
+/// * base_slippage_price - The price slippage is measured from for a MARKET_RANGE order
+/// * slippage_in_points - The slippage allowed for MARKET_RANGE and STOP_LIMIT orders
+/// * label - Free text of up to 100 characters
+/// * position_id - The position the order modifies, if any
+/// * client_order_id - Identifier of up to 50 characters chosen by the client
+/// * relative_stop_loss - Stop loss distance from the entry price in 1/100000 of a price unit
+/// * relative_take_profit - Take profit distance from the entry price in 1/100000 of a price unit
+/// * guaranteed_stop_loss - Whether the stop loss is guaranteed
+/// * trailing_stop_loss - Whether the stop loss trails the price
+/// * stop_trigger_method - The price triggering a STOP or STOP_LIMIT order
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRequest {
+    pub(crate) symbol_id: i64,
+    pub(crate) order_type: ProtoOaOrderType,
+    pub(crate) trade_side: ProtoOaTradeSide,
+    pub(crate) volume: i64,
+    pub(crate) limit_price: Option<f64>,
+    pub(crate) stop_price: Option<f64>,
+    pub(crate) time_in_force: Option<ProtoOaTimeInForce>,
+    pub(crate) expiration_timestamp: Option<i64>,
+    pub(crate) stop_loss: Option<f64>,
+    pub(crate) take_profit: Option<f64>,
+    pub(crate) comment: Option<String>,
+    pub(crate) base_slippage_price: Option<f64>,
+    pub(crate) slippage_in_points: Option<i32>,
+    pub(crate) label: Option<String>,
+    pub(crate) position_id: Option<i64>,
+    pub(crate) client_order_id: Option<String>,
+    pub(crate) relative_stop_loss: Option<i64>,
+    pub(crate) relative_take_profit: Option<i64>,
+    pub(crate) guaranteed_stop_loss: Option<bool>,
+    pub(crate) trailing_stop_loss: Option<bool>,
+    pub(crate) stop_trigger_method: Option<ProtoOaOrderTriggerMethod>,
+}
+
+/// The changes to make to a pending order, see `CTraderClient::send_amend_order_request`.
+/// Fields left to `None` keep their current value.
+/// * volume - The volume in 0.01 of a unit, e.g. 1000 means 10.00 units
+/// * limit_price - The price of a LIMIT order
+/// * stop_price - The trigger price of a STOP or STOP_LIMIT order
+/// * expiration_timestamp - Unix time in ms a GOOD_TILL_DATE order expires at
+/// * stop_loss - Absolute stop loss price
+/// * take_profit - Absolute take profit price
+/// * slippage_in_points - The slippage allowed for MARKET_RANGE and STOP_LIMIT orders
+/// * relative_stop_loss - Stop loss distance from the entry price in 1/100000 of a price unit
+/// * relative_take_profit - Take profit distance from the entry price in 1/100000 of a price unit
+/// * guaranteed_stop_loss - Whether the stop loss is guaranteed
+/// * trailing_stop_loss - Whether the stop loss trails the price
+/// * stop_trigger_method - The price triggering a STOP or STOP_LIMIT order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AmendOrder {
+    pub volume: Option<i64>,
+    pub limit_price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub expiration_timestamp: Option<i64>,
+    pub stop_loss: Option<f64>,
+    pub take_profit: Option<f64>,
+    pub slippage_in_points: Option<i32>,
+    pub relative_stop_loss: Option<i64>,
+    pub relative_take_profit: Option<i64>,
+    pub guaranteed_stop_loss: Option<bool>,
+    pub trailing_stop_loss: Option<bool>,
+    pub stop_trigger_method: Option<ProtoOaOrderTriggerMethod>,
+}
+
+/// Requests awaiting a response, keyed by the `client_msg_id` they were sent with.
+pub(crate) type PendingRequests = Arc<Mutex<HashMap<String, oneshot::Sender<Frame>>>>;
+
+/// The wire format used to talk to the CTrader OpenAPI.
+/// * Protobuf - Binary `ProtoMessage` frames on port 5035
+/// * Json - JSON text frames on port 5036, easier to inspect while debugging
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Transport {
+    #[default]
+    Protobuf,
+    Json,
+}
+
+/// The CTrader server the client connects to.
+/// * Demo - The demo server (<wss://demo.ctraderapi.com>)
+/// * Live - The live server (<wss://live.ctraderapi.com>)
+/// * Custom - Any other websocket URL including its port, e.g. a proxy or a local mock server
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Environment {
+    #[default]
+    Demo,
+    Live,
+    Custom {
+        url: String,
+    },
+}
+
+/// Collects the settings of a `CTraderClient` before connecting it.
+/// * environment - The server to connect to
+/// * transport - The wire format spoken on the websocket
+/// * auth - The application and account credentials
+/// * token_store - Where the credentials are loaded from and rotated tokens saved to
+/// * request_timeout - How long to wait for the response to a request
+/// * connect_timeout - How long a single connection attempt may take
+/// * heartbeat_interval - How long the connection may stay idle before a heartbeat is sent
+/// * read_idle_timeout - How long the server may stay silent before the connection is given up
+/// * reconnect_policy - How often and how fast to retry a failed connection attempt
+/// * event_buffer - Number of events buffered for each subscriber before it starts lagging
+#[derive(Debug, Clone)]
+pub struct CTraderClientBuilder {
+    pub(crate) environment: Environment,
+    pub(crate) transport: Transport,
+    pub(crate) auth: Option<Auth>,
+    pub(crate) token_store: Option<Arc<dyn TokenStore>>,
+    pub(crate) request_timeout: Duration,
+    pub(crate) connect_timeout: Duration,
+    pub(crate) heartbeat_interval: Duration,
+    pub(crate) read_idle_timeout: Duration,
+    pub(crate) reconnect_policy: ReconnectPolicy,
+    pub(crate) event_buffer: usize,
+}
+
+/// Saves the credentials as JSON in a file only readable by the current user.
+/// * path - The file the credentials are saved to
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    pub path: PathBuf,
+}
+
+/// Keeps the credentials in memory, for tests and short lived processes.
+/// * auth - The last saved credentials
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    pub(crate) auth: std::sync::Mutex<Option<Auth>>,
 }
 
 /// The representation of the response from the get token request.
-#[derive(Serialize, Deserialize)]
-pub struct TokenResponse;
+/// Accepts both the standard OAuth field names and the camelCase ones CTrader sends.
+/// * access_token - Token authorizing requests on behalf of the trading accounts
+/// * refresh_token - Token used to get a new access token once this one expires
+/// * expires_in - Lifetime of the access token in seconds
+/// * token_type - Type of the access token, usually `bearer`
+/// * error - The OAuth error code when the request was rejected
+/// * error_description - Details about `error`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TokenResponse {
+    #[serde(default, alias = "accessToken")]
+    pub access_token: Option<Secret>,
+    #[serde(default, alias = "refreshToken")]
+    pub refresh_token: Option<Secret>,
+    #[serde(default, alias = "expiresIn")]
+    pub expires_in: Option<u64>,
+    #[serde(default, alias = "tokenType")]
+    pub token_type: Option<String>,
+    #[serde(default, alias = "errorCode")]
+    pub error: Option<String>,
+    #[serde(default, alias = "description")]
+    pub error_description: Option<String>,
+}
+
+/// Messages pushed by the CTrader OpenAPI that are not tied to a request.
+/// * Spot - New bid/ask prices or live trendbars for a subscribed symbol
+/// * Execution - An order or position was accepted, filled, amended or cancelled
+/// * Depth - Changes to the depth of market of a subscribed symbol
+/// * Error - An error response that no pending request was waiting for
+/// * CommonError - A `ProtoErrorRes` shared by every CTrader API that no pending request was
+///   waiting for
+/// * ConnectionUnhealthy - A frame could not be written to the websocket or none was received
+///   within the `read_idle_timeout`
+/// * Disconnected - The connection dropped, a reconnect is in progress
+/// * Reconnected - The connection and the session were restored after a drop
+/// * TokensRotated - The access token was refreshed, persist the new tokens to keep them
+/// * Lagged - The consumer fell behind and `skipped` events were dropped for it
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum Event {
+    Spot(ProtoOaSpotEvent),
+    Execution(ProtoOaExecutionEvent),
+    Depth(ProtoOaDepthEvent),
+    OrderError(ProtoOaOrderErrorEvent),
+    TrailingStopLossChanged(ProtoOaTrailingSlChangedEvent),
+    MarginChanged(ProtoOaMarginChangedEvent),
+    MarginCallUpdated(ProtoOaMarginCallUpdateEvent),
+    MarginCallTriggered(ProtoOaMarginCallTriggerEvent),
+    SymbolChanged(ProtoOaSymbolChangedEvent),
+    TraderUpdated(ProtoOaTraderUpdatedEvent),
+    AccountDisconnected(ProtoOaAccountDisconnectEvent),
+    ClientDisconnected(ProtoOaClientDisconnectEvent),
+    AccountsTokenInvalidated(ProtoOaAccountsTokenInvalidatedEvent),
+    Error(ProtoOaErrorRes),
+    CommonError(ProtoErrorRes),
+    Heartbeat,
+    ConnectionUnhealthy {
+        reason: String,
+    },
+    Disconnected,
+    Reconnected,
+    TokensRotated {
+        access_token: Secret,
+        refresh_token: Secret,
+        expires_at: Option<SystemTime>,
+    },
+    Lagged {
+        skipped: u64,
+    },
+}
+
+/// How the client retries after the connection could not be established or was lost.
+/// * initial_backoff - Delay before the first retry
+/// * max_backoff - Upper bound of the delay between two retries
+/// * multiplier - Factor applied to the delay after each failed attempt, at least 1
+/// * max_attempts - Retries before giving up with RetriesExhausted, None to retry forever
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+/// What has to be restored on a new connection after a drop.
+/// * accounts - Trading accounts authorized with `ProtoOaAccountAuthReq`
+/// * spots - Spot subscriptions per account and symbol, with their `subscribe_to_spot_timestamp`
+/// * trendbars - Live trendbar subscriptions as (account, symbol, period)
+/// * depth - Depth of market subscriptions as (account, symbol)
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    pub accounts: BTreeSet<i64>,
+    pub spots: BTreeMap<(i64, i64), bool>,
+    pub trendbars: BTreeSet<(i64, i64, i32)>,
+    pub depth: BTreeSet<(i64, i64)>,
+}